@@ -0,0 +1,80 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use logic_parser::solver::{espresso, quine_mccluskey, Minterm};
+
+use std::time::{Duration, Instant};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Compares the Espresso-Heuristic against exact Quine-McCluskey Minimization on random
+/// Functions with few Variables (exact Minimization of random Functions gets out of Hand soon
+/// after), run with `cargo run --release --example espresso_benchmark`.
+fn main() {
+    const FUNCTIONS: usize = 20;
+    // Seeded, so every Run measures the same Functions
+    let mut rng = fastrand::Rng::with_seed(0x9E37_79B9_7F4A_7C15);
+
+    println!(
+        "{:>4} | {:>13} {:>13} | {:>13} {:>13} | {:>7} | {:>10} {:>10}",
+        "vars",
+        "exact terms",
+        "exact lits",
+        "heur. terms",
+        "heur. lits",
+        "optimal",
+        "exact",
+        "heuristic"
+    );
+    for n in 3..=6 {
+        let (mut exact_cost, mut heuristic_cost, mut optimal) = ((0, 0), (0, 0), 0);
+        let (mut exact_time, mut heuristic_time) = (Duration::ZERO, Duration::ZERO);
+        for _ in 0..FUNCTIONS {
+            let (on_set, off_set): (Vec<usize>, Vec<usize>) =
+                (0..1usize << n).partition(|_| rng.bool());
+
+            let start = Instant::now();
+            let exact = quine_mccluskey::minimize(&on_set, n);
+            exact_time += start.elapsed();
+
+            let cubes = |set: &[usize]| -> Vec<Minterm> {
+                set.iter().map(|i| Minterm::from_index(*i, n)).collect()
+            };
+            let start = Instant::now();
+            let heuristic = espresso::minimize(&cubes(&on_set), &[], &cubes(&off_set));
+            heuristic_time += start.elapsed();
+
+            let (exact, heuristic) = (cost(&exact), cost(&heuristic));
+            if (heuristic == exact) {
+                optimal += 1;
+            }
+            exact_cost = (exact_cost.0 + exact.0, exact_cost.1 + exact.1);
+            heuristic_cost = (
+                heuristic_cost.0 + heuristic.0,
+                heuristic_cost.1 + heuristic.1,
+            );
+        }
+        println!(
+            "{:>4} | {:>13} {:>13} | {:>13} {:>13} | {:>4}/{:<2} | {:>8}us {:>8}us",
+            n,
+            exact_cost.0,
+            exact_cost.1,
+            heuristic_cost.0,
+            heuristic_cost.1,
+            optimal,
+            FUNCTIONS,
+            exact_time.as_micros(),
+            heuristic_time.as_micros()
+        );
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Number of Terms and Literals of a Cover.
+fn cost(cover: &[Minterm]) -> (usize, usize) {
+    (cover.len(), cover.iter().map(Minterm::num_literals).sum())
+}
+
+/* --------------------------------------------------------------------------------------------- */
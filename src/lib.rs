@@ -1,10 +1,20 @@
 mod parser;
+pub mod solver;
 
 // Reexport public Items
 pub use parser::{
     ast_node::ASTNode,
-    operators::{InfixOperator, UnaryOperator},
-    parsing::Parsed,
+    binding::{Assignments, BindingOrder, Bindings},
+    dialect::{Dialect, Notation},
+    error::{EvaluationError, NormalFormError, ParseError},
+    karnaugh_map::KarnaughMap,
+    normal_form::{Clause, Cnf, Cube, Dnf, Literal, NormalFormOptions},
+    operators::{Assoc, InfixOperator, Operator, UnaryOperator},
+    parsing::{Assignment, DisplayWith, DontCares, Minimized, Parsed, Satisfiability, Verdict},
+    printer::Printer,
+    simplifier::{Law, RewriteStep},
+    truth_table::{Row, TableFormat, TruthTable},
+    tseitin::{Encoding, TseitinCnf},
 };
 
 #[cfg(test)]
@@ -12,4 +22,1467 @@ mod tests {
     pub use super::*;
     pub use parser::ast_node::ASTNode;
     pub use parser::operators::InfixOperator;
+
+    fn simplified(s: &str) -> String {
+        format!("{:#}", Parsed::new(s).unwrap().try_simplify())
+    }
+
+    /// Checks that both Expressions agree on every Assignment of their Variables.
+    fn assert_same_function(a: &Parsed, b: &Parsed) {
+        let n = a.num_variables().max(b.num_variables());
+        for row in 0..(1usize << n) {
+            let binding: Vec<(usize, bool)> = (0..n).map(|i| (i, (row >> i) & 1 == 1)).collect();
+            assert_eq!(
+                a.evaluate(&binding),
+                b.evaluate(&binding),
+                "{:#} / {:#}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn xor_and_equivalence_spellings() {
+        for s in ["A ^ B", "A xor B", "A XOR B", "A ⊕ B"] {
+            assert_eq!(format!("{:#}", Parsed::new(s).unwrap()), "A ^ B");
+        }
+        for s in ["A <-> B", "A <=> B", "A iff B", "A IFF B", "A ↔ B"] {
+            assert_eq!(format!("{:#}", Parsed::new(s).unwrap()), "A <-> B");
+        }
+    }
+
+    #[test]
+    fn xor_and_equivalence_apply() {
+        for (l, r) in [(false, false), (false, true), (true, false), (true, true)] {
+            assert_eq!(InfixOperator::Xor.apply(l, r), l != r);
+            assert_eq!(InfixOperator::Equivalence.apply(l, r), l == r);
+        }
+        let a = || ASTNode::Identifier(0);
+        assert_eq!(
+            InfixOperator::Xor.simplify(a(), a()),
+            ASTNode::Literal(false)
+        );
+        assert_eq!(
+            InfixOperator::Equivalence.simplify(a(), a()),
+            ASTNode::Literal(true)
+        );
+    }
+
+    #[test]
+    fn xor_simplification_identities() {
+        assert_eq!(simplified("A ^ A"), "false");
+        assert_eq!(simplified("A ^ false"), "A");
+        assert_eq!(simplified("false ^ A"), "A");
+        assert_eq!(simplified("A ^ true"), "!A");
+        assert_eq!(simplified("true ^ A"), "!A");
+        assert_eq!(simplified("!A ^ true"), "A");
+    }
+
+    #[test]
+    fn equivalence_simplification_identities() {
+        assert_eq!(simplified("A <-> A"), "true");
+        assert_eq!(simplified("A <-> true"), "A");
+        assert_eq!(simplified("true <-> A"), "A");
+        assert_eq!(simplified("A <-> false"), "!A");
+        assert_eq!(simplified("false <-> A"), "!A");
+    }
+
+    #[test]
+    fn simplification_keeps_function() {
+        for s in [
+            "A | A",
+            "A -> A",
+            "A -> false",
+            "false -> A",
+            "A ^ (B <-> true)",
+            "(A <-> B) ^ (A xor false)",
+        ] {
+            let parsed = Parsed::new(s).unwrap();
+            assert_same_function(&parsed, &parsed.clone().try_simplify());
+        }
+    }
+
+    const INFIX_OPERATORS: [InfixOperator; 5] = [
+        InfixOperator::And,
+        InfixOperator::Xor,
+        InfixOperator::Or,
+        InfixOperator::Implication,
+        InfixOperator::Equivalence,
+    ];
+
+    fn same_tree(a: &str, b: &str) -> bool {
+        format!("{:?}", Parsed::new(a).unwrap()) == format!("{:?}", Parsed::new(b).unwrap())
+    }
+
+    #[test]
+    fn precedence_table_order() {
+        let not = UnaryOperator::Not.precedence();
+        let precedences: Vec<usize> = INFIX_OPERATORS.iter().map(|op| op.precedence()).collect();
+        assert!(precedences.iter().all(|p| *p < not));
+        assert!(precedences.windows(2).all(|w| w[0] > w[1]));
+        assert_eq!(InfixOperator::Implication.assoc(), Assoc::Right);
+        assert_eq!(InfixOperator::And.assoc(), Assoc::Left);
+    }
+
+    #[test]
+    fn grammar_levels_match_precedence() {
+        let grammar = include_str!("my_grammar.pest");
+        let mut operators = INFIX_OPERATORS;
+        operators.sort_by_key(|op| op.precedence());
+        let rule = |op: &InfixOperator| format!("{:?}Expression", op);
+        assert!(grammar.contains(&format!("\nExpression = {{ {} }}", rule(&operators[0]))));
+        for (idx, op) in operators.iter().enumerate() {
+            let tighter = match operators.get(idx + 1) {
+                Some(next) => rule(next),
+                None => "UnaryExpression".to_string(),
+            };
+            let level = format!(
+                "\n{} = {{ {} ~ (Operation{:?} ~ {})* }}",
+                rule(op),
+                tighter,
+                op,
+                tighter
+            );
+            assert!(grammar.contains(&level), "{}", level);
+        }
+    }
+
+    #[test]
+    fn precedence_regressions() {
+        assert!(same_tree("A | B & C", "A | (B & C)"));
+        assert!(same_tree("A & B -> C", "(A & B) -> C"));
+        assert!(same_tree("A -> B <-> C", "(A -> B) <-> C"));
+        assert!(same_tree("!A & B", "(!A) & B"));
+        assert!(!same_tree("A | B & C", "(A | B) & C"));
+    }
+
+    #[test]
+    fn precedence_of_every_operator_pair() {
+        for op1 in INFIX_OPERATORS {
+            for op2 in INFIX_OPERATORS {
+                let input = format!("A {} B {} C", op1, op2);
+                let left = format!("(A {} B) {} C", op1, op2);
+                let right = format!("A {} (B {} C)", op1, op2);
+                let groups_right = op1.precedence() < op2.precedence()
+                    || (op1.precedence() == op2.precedence() && op1.assoc() == Assoc::Right);
+                let expected = if groups_right { &right } else { &left };
+                assert!(
+                    same_tree(&input, expected),
+                    "{} should be {}",
+                    input,
+                    expected
+                );
+            }
+            assert!(same_tree(
+                &format!("!A {} B", op1),
+                &format!("(!A) {} B", op1)
+            ));
+            assert!(same_tree(
+                &format!("A {} !B", op1),
+                &format!("A {} (!B)", op1)
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_error_location() {
+        let err = Parsed::new("A & (B | C D)").unwrap_err();
+        assert_eq!(err.span(), 11..12);
+        assert_eq!((err.line(), err.column()), (1, 12));
+        assert_eq!(err.found(), "D");
+        assert!(err.expected().contains(&"`)`".to_string()));
+
+        let err = Parsed::new("A &\n  | B").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+        assert!(matches!(err, ParseError::UnexpectedToken { .. }));
+
+        let err = Parsed::new("A ->").unwrap_err();
+        assert!(matches!(err, ParseError::UnexpectedEndOfInput { .. }));
+        assert_eq!(err.span(), 4..4);
+    }
+
+    #[test]
+    fn parse_error_render() {
+        let err: Box<dyn std::error::Error> = Box::new(Parsed::new("A & ∧x").unwrap_err());
+        assert_eq!(err.to_string(), "unexpected `∧`, expected expression");
+        let err = Parsed::new("foo | bar baz").unwrap_err();
+        assert_eq!(
+            err.render(),
+            "error: unexpected `baz`, expected one of end of input, `<->`, `->`, `|`, `^`, `&`\n\
+             \x20--> 1:11\n\
+             \x20 |\n\
+             1 | foo | bar baz\n\
+             \x20 |           ^^^\n"
+        );
+    }
+
+    #[test]
+    fn identifier_syntax() {
+        for s in ["x1", "enable_n", "_reset", "cpu.ready", "cpu.core_0.ready"] {
+            assert_eq!(format!("{:#}", Parsed::new(s).unwrap()), s);
+        }
+        assert_eq!(
+            format!("{:#}", Parsed::new("\"signal with spaces\"").unwrap()),
+            "\"signal with spaces\""
+        );
+        assert_eq!(
+            format!("{:#}", Parsed::new("`say \"hi\"`").unwrap()),
+            "`say \"hi\"`"
+        );
+        // Quoting a plain Name doesn't change the Variable
+        assert_eq!(Parsed::new("\"A\" & A & `A`").unwrap().num_variables(), 1);
+        // Keywords need a Word-Boundary
+        assert_eq!(Parsed::new("android | order").unwrap().num_variables(), 2);
+        assert_eq!(Parsed::new("truex & falsey").unwrap().num_variables(), 2);
+        for s in ["1x", "a..b", "a.", "and", "A & or", "\"\""] {
+            assert!(Parsed::new(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn identifier_roundtrip() {
+        for s in [
+            "x1 & \"signal with spaces\" | cpu.ready",
+            "\"and\" ^ \"1x\" -> `\"quoted\"`",
+            "\"true\" <-> true",
+            "\"say \"\"hi\"\" with `x`\" & y",
+        ] {
+            let printed = format!("{:#}", Parsed::new(s).unwrap());
+            assert_eq!(printed, s);
+            assert!(same_tree(s, &printed));
+        }
+        // A doubled Quote stands for the Quote itself
+        let parsed = Parsed::new("\"a\"\"b`\" | `c``d\"`").unwrap();
+        let names: Vec<&str> = parsed.variable_names().collect();
+        assert_eq!(names, ["a\"b`", "c`d\""]);
+        assert_eq!(format!("{:#}", parsed), "\"a\"\"b`\" | \"c`d\"\"\"");
+    }
+
+    #[test]
+    fn unicode_and_latex_input() {
+        let expected = "!A & B | C -> D <-> E ^ true";
+        for (s, dialect) in [
+            ("!A & B | C -> D <-> E ^ true", Dialect::Ascii),
+            ("¬A ∧ B ∨ C → D ↔ E ⊕ ⊤", Dialect::Unicode),
+            ("¬A ∧ B ∨ C ⇒ D ⇔ E ⊕ ⊤", Dialect::Unicode),
+            (
+                r"\neg A \land B \lor C \to D \leftrightarrow E \oplus \top",
+                Dialect::Latex,
+            ),
+            (
+                r"\lnot A \wedge B \vee C \implies D \iff E \oplus \top",
+                Dialect::Latex,
+            ),
+            ("!A AND B OR C -> D <-> E ^ true", Dialect::Mixed),
+        ] {
+            let parsed = Parsed::new(s).unwrap();
+            assert!(same_tree(s, expected), "{}", s);
+            assert_eq!(parsed.dialect(), dialect, "{}", s);
+        }
+        assert_eq!(
+            Parsed::new("A and B or C").unwrap().dialect(),
+            Dialect::Word
+        );
+        assert_eq!(Parsed::new("A").unwrap().dialect(), Dialect::Ascii);
+        assert_eq!(Parsed::new("A & true").unwrap().dialect(), Dialect::Ascii);
+        assert!(same_tree(r"\bot \lor ⊥", "false | false"));
+    }
+
+    #[test]
+    fn latex_commands_need_boundary() {
+        assert!(same_tree(r"A \to \top", "A -> true"));
+        let err = Parsed::new(r"A \landB").unwrap_err();
+        assert_eq!(err.column(), 3);
+        assert_eq!(err.found(), r"\landB");
+    }
+
+    #[test]
+    fn display_with_notation() {
+        let parsed = Parsed::new("!(A & B) | C -> D ^ true <-> !E").unwrap();
+        let cases = [
+            (Notation::Ascii, "!(A & B) | C -> D ^ true <-> !E"),
+            (Notation::Unicode, "¬(A ∧ B) ∨ C → D ⊕ ⊤ ↔ ¬E"),
+            (
+                Notation::Word,
+                "NOT (A AND B) OR C IMPLIES D XOR TRUE IFF NOT E",
+            ),
+            (Notation::CStyle, "(!(!(A && B) || C) || D != true) == !E"),
+            (
+                Notation::Latex,
+                r"\neg (A \land B) \lor C \to D \oplus \top \leftrightarrow \neg E",
+            ),
+        ];
+        for (notation, expected) in cases {
+            let printed = parsed.display_with(notation).to_string();
+            assert_eq!(printed, expected);
+            if notation != Notation::CStyle {
+                assert!(same_tree(&printed, "!(A & B) | C -> D ^ true <-> !E"));
+            }
+        }
+    }
+
+    #[test]
+    fn word_operators_are_reserved() {
+        assert!(same_tree("NOT a IMPLIES b", "!a -> b"));
+        assert!(same_tree("not a implies b", "!a -> b"));
+        for s in ["not", "implies", "a & not", "implies | b", "Not & a"] {
+            assert!(Parsed::new(s).is_err(), "{}", s);
+        }
+        let parsed = Parsed::new("\"not\" & `implies` | nothing | implies_x").unwrap();
+        assert_eq!(parsed.num_variables(), 4);
+        assert_eq!(
+            format!("{:#}", parsed),
+            "\"not\" & \"implies\" | nothing | implies_x"
+        );
+    }
+
+    #[test]
+    fn display_in_input_dialect() {
+        let parsed = Parsed::new(r"\neg A \lor \bot").unwrap();
+        let notation = Notation::from(parsed.dialect());
+        assert_eq!(
+            parsed.display_with(notation).to_string(),
+            r"\neg A \lor \bot"
+        );
+        assert_eq!(Notation::from(Dialect::Mixed), Notation::Ascii);
+    }
+
+    /// Every Tree of two Operators over three Variables, optionally negated.
+    fn small_trees() -> Vec<ASTNode> {
+        let var = ASTNode::Identifier;
+        let mut trees = Vec::new();
+        for op1 in INFIX_OPERATORS {
+            for op2 in INFIX_OPERATORS {
+                let left = ASTNode::infix(op2, ASTNode::infix(op1, var(0), var(1)), var(2));
+                let right = ASTNode::infix(op1, var(0), ASTNode::infix(op2, var(1), var(2)));
+                for tree in [left, right] {
+                    trees.push(tree.clone().negate());
+                    trees.push(tree);
+                }
+            }
+        }
+        trees
+    }
+
+    #[test]
+    fn minimal_braces_roundtrip() {
+        for tree in small_trees() {
+            for notation in [
+                Notation::Ascii,
+                Notation::Unicode,
+                Notation::Word,
+                Notation::Latex,
+            ] {
+                let printed = Printer::new()
+                    .with_notation(notation)
+                    .print(&tree, &["A", "B", "C"]);
+                let reparsed = Parsed::new(&printed).unwrap();
+                assert_eq!(
+                    format!("{:?}", reparsed),
+                    format!("{:?}", tree),
+                    "{}",
+                    printed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn minimal_braces() {
+        let parsed = Parsed::new("(A & B) | (C & (D | E)) | (F -> G -> H) & !(I ^ J)").unwrap();
+        assert_eq!(
+            format!("{:#}", parsed),
+            "A & B | C & (D | E) | (F -> G -> H) & !(I ^ J)"
+        );
+        assert_eq!(
+            format!("{:b}", parsed),
+            "0 & 1 | 2 & (3 | 4) | (5 -> 6 -> 7) & !(8 ^ 9)"
+        );
+        assert_eq!(
+            format!("{:#}", Parsed::new("(A -> B) -> C").unwrap()),
+            "(A -> B) -> C"
+        );
+        assert_eq!(
+            format!("{:#}", Parsed::new("A | (B | C)").unwrap()),
+            "A | (B | C)"
+        );
+    }
+
+    #[test]
+    fn printer_max_width() {
+        let parsed = Parsed::new(
+            "alpha & beta & gamma & delta | (epsilon & zeta) | !(eta | theta | iota | kappa)",
+        )
+        .unwrap();
+        let printed = parsed.print(&Printer::new().with_max_width(30));
+        assert_eq!(
+            printed,
+            "alpha & beta & gamma & delta\n\
+             | epsilon & zeta\n\
+             | !(eta | theta | iota | kappa)"
+        );
+        let printed = parsed.print(&Printer::new().with_max_width(20));
+        assert_eq!(
+            printed,
+            "alpha\n\
+             \x20 & beta\n\
+             \x20 & gamma\n\
+             \x20 & delta\n\
+             | epsilon & zeta\n\
+             | !(eta | theta | iota | kappa)"
+        );
+        assert!(same_tree(&printed, &format!("{:#}", parsed)));
+    }
+
+    #[test]
+    fn owned_parsed() {
+        let mut cache: std::collections::HashMap<String, Parsed<'static>> = Default::default();
+        for input in ["cpu.ready & !reset", "a | \"b c\""] {
+            let temporary = input.to_string();
+            let parsed = Parsed::new(&temporary).unwrap().into_owned();
+            drop(temporary);
+            cache.insert(input.to_string(), parsed);
+        }
+        let parsed = cache.remove("a | \"b c\"").unwrap();
+        let printed = std::thread::spawn(move || format!("{:#}", parsed))
+            .join()
+            .unwrap();
+        assert_eq!(printed, "a | \"b c\"");
+        let names: Vec<&str> = cache["cpu.ready & !reset"].variable_names().collect();
+        assert_eq!(names, ["cpu.ready", "reset"]);
+    }
+
+    #[test]
+    fn evaluate_named_and_indexed() {
+        let parsed = Parsed::new("a & !b | c").unwrap();
+        let env: std::collections::HashMap<&str, bool> =
+            [("a", true), ("b", false), ("c", false)].into();
+        assert!(parsed.evaluate_with(&env));
+        assert_eq!(parsed.try_evaluate(&env), Ok(true));
+        assert!(parsed.evaluate_slice(&[true, false, false]));
+        assert!(!parsed.evaluate_slice(&[true, true, false]));
+
+        let env: std::collections::HashMap<&str, bool> = [("a", true), ("b", true)].into();
+        let err = parsed.try_evaluate(&env).unwrap_err();
+        assert_eq!(err, EvaluationError::UnboundVariable("c".to_string()));
+        assert_eq!(err.to_string(), "unbound variable `c`");
+
+        // Variables removed by the Simplification don't need a Value
+        let simplified = Parsed::new("a | true").unwrap().try_simplify();
+        assert_eq!(simplified.try_evaluate(&Default::default()), Ok(true));
+    }
+
+    #[test]
+    #[should_panic(expected = "unbound variable `x`")]
+    fn evaluate_with_unbound_variable() {
+        Parsed::new("x").unwrap().evaluate_with(&Default::default());
+    }
+
+    #[test]
+    fn truth_table_rows() {
+        let table = Parsed::new("a -> b").unwrap().truth_table();
+        assert_eq!(table.variables(), ["a", "b"]);
+        assert_eq!(table.outputs(), [true, true, false, true]);
+        assert_eq!(
+            table.row(2),
+            Row {
+                index: 2,
+                inputs: vec![true, false],
+                output: false
+            }
+        );
+        let true_rows: Vec<usize> = table.true_rows().map(|row| row.index).collect();
+        assert_eq!(true_rows, [0, 1, 3]);
+        assert_eq!(table.false_rows().count(), 1);
+        assert_eq!(Parsed::new("true").unwrap().truth_table().len(), 1);
+    }
+
+    #[test]
+    fn truth_table_render() {
+        let table = Parsed::new("a | long_name").unwrap().truth_table();
+        assert_eq!(
+            table.render(TableFormat::Ascii),
+            "a long_name | a | long_name\n\
+             ------------+--------------\n\
+             0 0         | 0\n\
+             0 1         | 1\n\
+             1 0         | 1\n\
+             1 1         | 1\n"
+        );
+        assert_eq!(table.to_string(), table.render(TableFormat::Ascii));
+        assert!(table
+            .render(TableFormat::Markdown)
+            .starts_with("| a | long_name | a \\| long_name |\n| - | --------- | -------------- |\n| 0 | 0         | 0              |\n"));
+        assert_eq!(
+            Parsed::new("\"a|b\" | c")
+                .unwrap()
+                .truth_table()
+                .render(TableFormat::Markdown)
+                .lines()
+                .next(),
+            Some("| a\\|b | c | \"a\\|b\" \\| c |")
+        );
+        assert_eq!(
+            Parsed::new("\"x,y\" & z")
+                .unwrap()
+                .truth_table()
+                .render(TableFormat::Csv)
+                .lines()
+                .next(),
+            Some("\"x,y\",z,\"\"\"x,y\"\" & z\"")
+        );
+        let latex = table.render(TableFormat::Latex);
+        assert!(latex.starts_with(
+            "\\begin{tabular}{cc|c}\n\
+             $\\text{a}$ & $\\text{long\\_name}$ & $\\text{a} \\lor \\text{long\\_name}$ \\\\\n\\hline\n\
+             0 & 0 & 0 \\\\\n"
+        ));
+        assert!(latex.ends_with("1 & 1 & 1 \\\\\n\\end{tabular}\n"));
+        let latex = Parsed::new("\"R&D %\" | `#1`")
+            .unwrap()
+            .truth_table()
+            .render(TableFormat::Latex);
+        assert!(latex.contains(
+            "$\\text{R\\&D \\%}$ & $\\text{\\#1}$ & $\\text{R\\&D \\%} \\lor \\text{\\#1}$ \\\\\n"
+        ));
+    }
+
+    #[test]
+    fn bindings_binary_order() {
+        let assignments: Vec<Vec<bool>> = Bindings::from(vec!["a", "b"]).assignments().collect();
+        assert_eq!(
+            assignments,
+            [[false, false], [false, true], [true, false], [true, true]]
+        );
+        let mut bindings = Bindings::from(vec!["a", "b", "c"]);
+        let changed: Vec<usize> = bindings.by_ref().collect();
+        assert_eq!(changed, [2, 1, 2, 0, 2, 1, 2]);
+        // The Iterator doesn't wrap around
+        assert_eq!(bindings.next(), None);
+        assert_eq!(bindings.to_string(), "1, 1, 1");
+        bindings.reset_values();
+        assert_eq!(bindings.count(), 7);
+    }
+
+    #[test]
+    fn bindings_gray_order() {
+        let mut bindings = Bindings::from(vec!["a", "b", "c"]).with_order(BindingOrder::Gray);
+        let mut seen = vec![bindings.to_vec()];
+        let mut previous = bindings.to_vec();
+        while let Some(idx) = bindings.next() {
+            let current = bindings.to_vec();
+            let flipped: Vec<usize> = (0..3).filter(|i| previous[*i] != current[*i]).collect();
+            assert_eq!(flipped, [idx]);
+            seen.push(current.clone());
+            previous = current;
+        }
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 8);
+    }
+
+    #[test]
+    fn bindings_fixed_variables() {
+        let mut bindings = Bindings::from(vec![("a", true), ("b", true), ("c", false)]);
+        assert!(bindings.fix("b", false));
+        assert!(!bindings.fix("missing", true));
+        assert_eq!(bindings.num_assignments(), Some(4));
+        let assignments: Vec<Vec<bool>> = bindings.assignments().collect();
+        assert_eq!(assignments.len(), 4);
+        assert!(assignments.iter().all(|a| !a[1]));
+
+        assert_eq!(Bindings::new().to_string(), "");
+        assert_eq!(Bindings::new().assignments().count(), 1);
+        let names: Vec<String> = (0..usize::BITS).map(|i| format!("x{}", i)).collect();
+        let mut wide = Bindings::from(names.iter().map(String::as_str).collect::<Vec<_>>());
+        assert_eq!(wide.num_assignments(), None);
+        wide.fix_index(0, true);
+        assert_eq!(wide.num_assignments(), Some(1 << (usize::BITS - 1)));
+        assert_eq!(wide.next(), Some(names.len() - 1));
+        let parsed = Parsed::new("x & y").unwrap();
+        assert_eq!(parsed.bindings().get_name(1), "y");
+    }
+
+    #[test]
+    fn minterm_merge() {
+        use solver::minterm::Minterm;
+        let a = Minterm::from_index(0b101, 3);
+        let b = Minterm::from_index(0b111, 3);
+        let merged = a.merge(&b).unwrap();
+        assert_eq!(merged.to_string(), "TXT");
+        assert_eq!(merged.indices(), [0b101, 0b111]);
+        assert!(a.merge(&Minterm::from_index(0b010, 3)).is_none());
+        assert!(merged.covers(0b111) && !merged.covers(0b110));
+    }
+
+    #[test]
+    fn quine_mccluskey_prime_implicants() {
+        use solver::{minterm::Minterm, quine_mccluskey};
+        // f(a, b, c, d) = Σm(4, 8, 10, 11, 12, 15) without Don't-Cares
+        let on_set = [4, 8, 10, 11, 12, 15];
+        let terms: Vec<Minterm> = on_set.iter().map(|i| Minterm::from_index(*i, 4)).collect();
+        let mut primes: Vec<String> = quine_mccluskey::prime_implicants(&terms)
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        primes.sort();
+        assert_eq!(primes, ["TFTX", "TFXF", "TXFF", "TXTT", "XTFF"]);
+        let cover = quine_mccluskey::minimize(&on_set, 4);
+        assert_eq!(cover.len(), 3);
+    }
+
+    #[test]
+    fn quine_mccluskey_exact_minimum() {
+        use solver::{minterm::Minterm, quine_mccluskey};
+        // Compare with the smallest Subset of Prime-Implicants for every Function of 3 Variables,
+        // including cyclic ones like Σm(0, 1, 2, 5, 6, 7) without essential Implicants
+        for function in 0usize..256 {
+            let on_set: Vec<usize> = (0..8).filter(|row| (function >> row) & 1 == 1).collect();
+            let terms: Vec<Minterm> = on_set.iter().map(|i| Minterm::from_index(*i, 3)).collect();
+            let primes = quine_mccluskey::prime_implicants(&terms);
+            let smallest = (0usize..(1 << primes.len()))
+                .filter(|subset| {
+                    on_set.iter().all(|row| {
+                        (0..primes.len()).any(|p| (subset >> p) & 1 == 1 && primes[p].covers(*row))
+                    })
+                })
+                .map(|subset| subset.count_ones() as usize)
+                .min()
+                .unwrap();
+            let cover = quine_mccluskey::minimize(&on_set, 3);
+            assert_eq!(cover.len(), smallest, "{:?}", on_set);
+            assert!(on_set
+                .iter()
+                .all(|row| cover.iter().any(|t| t.covers(*row))));
+        }
+    }
+
+    #[test]
+    fn minimize_keeps_function() {
+        for s in [
+            "a & b | a & !b",
+            "(a -> b) & (b -> c) & (c -> a)",
+            "a ^ b ^ c",
+            "!(a | b) | (a & b & c) | (!a & c)",
+            "a & !a",
+            "a | !a",
+            "(w | x) & (y | z) & !(w & y)",
+        ] {
+            let parsed = Parsed::new(s).unwrap();
+            let minimized = parsed.minimize();
+            assert_same_function(&parsed, &minimized);
+        }
+        assert_eq!(
+            format!("{:#}", Parsed::new("a & b | a & !b").unwrap().minimize()),
+            "a"
+        );
+        assert_eq!(
+            format!("{:#}", Parsed::new("a & !a").unwrap().minimize()),
+            "false"
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                Parsed::new("!a & b | a & !b | a & b").unwrap().minimize()
+            ),
+            "a | b"
+        );
+    }
+
+    #[test]
+    fn petrick_cyclic_cover() {
+        use solver::{minterm::Minterm, petrick, CoverCost};
+        // Σm(0, 1, 2, 5, 6, 7) has no essential Prime-Implicants and two minimal Covers
+        let on_set = [0, 1, 2, 5, 6, 7];
+        let terms: Vec<Minterm> = on_set.iter().map(|i| Minterm::from_index(*i, 3)).collect();
+        let primes = solver::quine_mccluskey::prime_implicants(&terms);
+        assert_eq!(primes.len(), 6);
+        let covers: Vec<Vec<String>> = petrick::minimal_covers(&primes, &on_set, CoverCost::Terms)
+            .iter()
+            .map(|cover| cover.iter().map(|t| t.to_string()).collect())
+            .collect();
+        assert_eq!(
+            covers,
+            [["TXT", "FFX", "XTF"], ["TTX", "FXF", "XFT"]].map(|c| c.map(String::from).to_vec())
+        );
+        assert_eq!(CoverCost::Terms.of(&primes[..2]), (2, 4));
+        assert_eq!(CoverCost::Literals.of(&primes[..2]), (4, 2));
+    }
+
+    #[test]
+    fn petrick_bound_keeps_covers() {
+        use solver::{minterm::Minterm, petrick, CoverCost};
+        // Every Function of 3 Variables and a Sample of those of 4 Variables
+        let functions = (0usize..256)
+            .map(|f| (f, 3))
+            .chain((0usize..65536).step_by(97).map(|f| (f, 4)));
+        for (function, num_variables) in functions {
+            let on_set: Vec<usize> = (0..(1 << num_variables))
+                .filter(|row| (function >> row) & 1 == 1)
+                .collect();
+            let terms: Vec<Minterm> = on_set
+                .iter()
+                .map(|i| Minterm::from_index(*i, num_variables))
+                .collect();
+            let primes = solver::quine_mccluskey::prime_implicants(&terms);
+            for cost in [CoverCost::Terms, CoverCost::Literals] {
+                assert_eq!(
+                    petrick::minimal_covers(&primes, &on_set, cost),
+                    petrick::minimal_covers_unbounded(&primes, &on_set, cost),
+                    "{:?} {:?}",
+                    on_set,
+                    cost
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn minimize_with_cost() {
+        use solver::CoverCost;
+        let parsed = Parsed::new("!a & !b | !a & b & !c | a & c | b & c & !a").unwrap();
+        for cost in [CoverCost::Terms, CoverCost::Literals] {
+            let minimized = parsed.minimize_with(cost);
+            assert_same_function(&parsed, &minimized);
+        }
+        assert_eq!(
+            format!("{:#}", parsed.minimize_with(CoverCost::Literals)),
+            format!("{:#}", parsed.minimize())
+        );
+
+        // Two Terms with six Literals against three Terms with five
+        let parsed = Parsed::new("f(a, b, c, d, e) = Σm(0, 3, 30)").unwrap();
+        let condition = Parsed::new("!a & !b & !c | a & b & c | !d | e").unwrap();
+        let table = condition.truth_table();
+        let rows = table.true_rows().map(|r| r.index);
+        let dont_cares = DontCares::Indices(rows.filter(|r| ![0, 3, 30].contains(r)).collect());
+        let minimized = |cost| {
+            let minimized = parsed.minimize_dont_care(&dont_cares, cost).unwrap();
+            format!("{:#}", minimized.parsed)
+        };
+        assert_eq!(minimized(CoverCost::Terms), "a & b & c | !a & !b & !c");
+        assert_eq!(minimized(CoverCost::Literals), "a & b & c | !d | e");
+    }
+
+    #[test]
+    fn espresso_keeps_function() {
+        for s in [
+            "a & b | a & !b",
+            "(a -> b) & (b -> c) & (c -> a)",
+            "a ^ b ^ c ^ d",
+            "!(a | b) | (a & b & c) | (!a & c)",
+            "a & !a",
+            "a | !a",
+            "(w | x) & (y | z) & !(w & y)",
+            "(a <-> b) -> (c | !d & a)",
+        ] {
+            let parsed = Parsed::new(s).unwrap();
+            let heuristic = parsed.minimize_heuristic();
+            assert_same_function(&parsed, &heuristic);
+            // Never worse than the Input and rarely worse than the exact Result
+            let exact = parsed.minimize();
+            let terms = |p: &Parsed| format!("{:#}", p).matches('|').count();
+            assert!(terms(&heuristic) <= terms(&exact) + 1, "{:#}", heuristic);
+        }
+    }
+
+    #[test]
+    fn espresso_many_variables() {
+        // 40 Variables, far too many for a Truth-Table
+        let input: Vec<String> = (0..20)
+            .map(|i| format!("a{0} & b{0} | a{0} & !b{0}", i))
+            .collect();
+        let input = input.join(" | ");
+        let parsed = Parsed::new(&input).unwrap();
+        assert_eq!(parsed.num_variables(), 40);
+        let expected: Vec<String> = (0..20).map(|i| format!("a{}", i)).collect();
+        assert_eq!(
+            format!("{:#}", parsed.minimize_heuristic()),
+            expected.join(" | ")
+        );
+    }
+
+    #[test]
+    fn espresso_cnf_shaped() {
+        // Multiplying the Clauses out would produce 2^10 Cubes before they collapse
+        let clauses: Vec<String> = (0..10)
+            .map(|i| format!("(x{0} | y{0} | a) & (!x{0} | y{0} | a)", i))
+            .collect();
+        let input = clauses.join(" & ");
+        let parsed = Parsed::new(&input).unwrap();
+        let expected: Vec<String> = (0..10).map(|i| format!("y{}", i)).collect();
+        assert_eq!(
+            format!("{:#}", parsed.minimize_heuristic()),
+            format!("{} | a", expected.join(" & "))
+        );
+
+        // 30 Variables that all imply each other in a Cycle
+        let clauses: Vec<String> = (0..30)
+            .map(|i| format!("(!v{} | v{})", i, (i + 1) % 30))
+            .collect();
+        let input = clauses.join(" & ");
+        let parsed = Parsed::new(&input).unwrap();
+        let all: Vec<String> = (0..30).map(|i| format!("v{}", i)).collect();
+        let none: Vec<String> = (0..30).map(|i| format!("!v{}", i)).collect();
+        assert_eq!(
+            format!("{:#}", parsed.minimize_heuristic()),
+            format!("{} | {}", all.join(" & "), none.join(" & "))
+        );
+    }
+
+    #[test]
+    fn minimize_with_dont_cares() {
+        use solver::CoverCost;
+        // BCD-Digit 9 detector: Rows 10 to 15 never occur
+        let parsed = Parsed::new("a & !b & !c & d").unwrap();
+        let minimized = parsed
+            .minimize_dont_care(&DontCares::Indices((10..16).collect()), CoverCost::Terms)
+            .unwrap();
+        assert_eq!(format!("{:#}", minimized.parsed), "a & d");
+        assert_eq!(minimized.dont_cares_true, [11, 13, 15]);
+        assert_eq!(minimized.dont_cares_false, [10, 12, 14]);
+
+        let condition = Parsed::new("a & b").unwrap();
+        let parsed = Parsed::new("a & !b | !a & b").unwrap();
+        let minimized = parsed
+            .minimize_dont_care(&DontCares::Expression(&condition), CoverCost::Terms)
+            .unwrap();
+        assert_eq!(format!("{:#}", minimized.parsed), "a | b");
+        assert_eq!(minimized.dont_cares_true, [3]);
+        // Outside of the Don't-Cares the Function is unchanged
+        for row in parsed.truth_table().rows().filter(|r| r.index != 3) {
+            assert_eq!(minimized.parsed.evaluate_slice(&row.inputs), row.output);
+        }
+
+        let unknown = Parsed::new("a & z").unwrap();
+        assert_eq!(
+            parsed
+                .minimize_dont_care(&DontCares::Expression(&unknown), CoverCost::Terms)
+                .unwrap_err(),
+            EvaluationError::UnboundVariable("z".to_string())
+        );
+        assert_eq!(
+            parsed
+                .minimize_dont_care(&DontCares::Indices(vec![4]), CoverCost::Terms)
+                .unwrap_err(),
+            EvaluationError::RowOutOfRange(4)
+        );
+    }
+
+    #[test]
+    fn minimize_heuristic_with_dont_cares() {
+        let parsed = Parsed::new("a & !b & !c & d").unwrap();
+        let minimized = parsed
+            .minimize_heuristic_dont_care(&DontCares::Indices((10..16).collect()))
+            .unwrap();
+        assert_eq!(format!("{:#}", minimized.parsed), "a & d");
+        assert_eq!(minimized.dont_cares_true, [11, 13, 15]);
+        assert_eq!(minimized.dont_cares_false, [10, 12, 14]);
+        let parsed = Parsed::new("f(a, b) = Σm(1) + d(3)").unwrap();
+        assert_eq!(format!("{:#}", parsed.minimize_heuristic()), "b");
+
+        // 42 Variables, only the single Don't-Care Row is listed
+        let wide: Vec<String> = (0..40).map(|i| format!("c{}", i)).collect();
+        let input = format!("a & !b & {}", wide.join(" & "));
+        let parsed = Parsed::new(&input).unwrap();
+        let condition = format!("b & a & {}", wide.join(" & "));
+        let condition = Parsed::new(&condition).unwrap();
+        let minimized = parsed
+            .minimize_heuristic_dont_care(&DontCares::Expression(&condition))
+            .unwrap();
+        assert_eq!(
+            format!("{:#}", minimized.parsed),
+            format!("a & {}", wide.join(" & "))
+        );
+        assert_eq!(minimized.dont_cares_true, [(1 << 42) - 1]);
+        assert!(minimized.dont_cares_false.is_empty());
+
+        let unknown = Parsed::new("a & z").unwrap();
+        assert_eq!(
+            parsed
+                .minimize_heuristic_dont_care(&DontCares::Expression(&unknown))
+                .unwrap_err(),
+            EvaluationError::UnboundVariable("z".to_string())
+        );
+        let parsed = Parsed::new("a ^ b").unwrap();
+        assert_eq!(
+            parsed
+                .minimize_heuristic_dont_care(&DontCares::Indices(vec![4]))
+                .unwrap_err(),
+            EvaluationError::RowOutOfRange(4)
+        );
+    }
+
+    #[test]
+    fn espresso_with_dont_cares() {
+        use solver::{espresso, Minterm};
+        let cubes = |rows: &[usize]| -> Vec<Minterm> {
+            rows.iter().map(|i| Minterm::from_index(*i, 4)).collect()
+        };
+        let cover = espresso::minimize(
+            &cubes(&[9]),
+            &cubes(&[10, 11, 12, 13, 14, 15]),
+            &cubes(&(0..9).collect::<Vec<_>>()),
+        );
+        assert_eq!(
+            cover.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+            ["TXXT"]
+        );
+    }
+
+    #[test]
+    fn minterm_notation_input() {
+        let parsed = Parsed::new("f(A,B,C) = Σm(1,3,5,7) + d(2)").unwrap();
+        assert_eq!(parsed.variable_names().collect::<Vec<_>>(), ["A", "B", "C"]);
+        assert_eq!(parsed.minterm_indices(), [1, 3, 5, 7]);
+        assert_eq!(parsed.dont_cares(), [2]);
+        assert_eq!(format!("{:#}", parsed.minimize()), "C");
+
+        let parsed = Parsed::new("f(a, b) = ΠM(0, 3)").unwrap();
+        assert_eq!(format!("{:#}", parsed), "(a | b) & (!a | !b)");
+        assert_eq!(parsed.maxterm_indices(), [0, 3]);
+        let parsed = Parsed::new("g(x, y) = sum m(3)").unwrap();
+        assert_eq!(format!("{:#}", parsed), "x & y");
+        let parsed = Parsed::new("g(x, y) = prod(1) * d(0)").unwrap();
+        assert_eq!(parsed.maxterm_indices(), [1]);
+        assert_eq!(parsed.dont_cares(), [0]);
+        assert_eq!(format!("{:#}", Parsed::new("c() = Σm()").unwrap()), "false");
+
+        let error = |s: &str| Parsed::new(s).unwrap_err().expected().to_vec();
+        assert_eq!(error("f(a, b) = Σm(1, 4)"), ["index below 4"]);
+        assert_eq!(error("f(a, a) = Σm(1)"), ["distinct variable"]);
+        assert_eq!(
+            error("f(a) = Σm(0) + d(0)"),
+            ["index that wasn't listed before"]
+        );
+        assert!(Parsed::new("f(a, b)").is_err());
+
+        // Row-Indices have to fit into a `usize`
+        let names: Vec<String> = (0..=usize::BITS).map(|i| format!("v{}", i)).collect();
+        let input = format!("f({}) = Σm(1)", names[..(usize::BITS as usize)].join(", "));
+        let err = Parsed::new(&input).unwrap_err();
+        assert_eq!(err.found(), names[usize::BITS as usize - 1]);
+        assert_eq!(
+            err.expected(),
+            [format!("`)` after {} variables", usize::BITS - 1)]
+        );
+        let input = format!("f({}) = Σm(1)", names[1..(usize::BITS as usize)].join(", "));
+        assert!(Parsed::new(&input).is_ok());
+        let wide = solver::minterm::Minterm::from_index(1, 70);
+        assert_eq!(wide.num_true(), 1);
+        assert_eq!(wide.values()[69], solver::TruthValue::True);
+        assert!(wide.covers(1) && !wide.covers(2));
+    }
+
+    #[test]
+    fn minterm_notation_output() {
+        let parsed = Parsed::new("a -> b").unwrap();
+        assert_eq!(parsed.minterm_indices(), [0, 1, 3]);
+        assert_eq!(parsed.sum_of_minterms("f"), "f(a, b) = Σm(0, 1, 3)");
+        assert_eq!(parsed.product_of_maxterms("f"), "f(a, b) = ΠM(2)");
+
+        let input = "f(x, \"y z\", w) = Σm(0, 6) + d(1, 7)";
+        let parsed = Parsed::new(input).unwrap();
+        assert_eq!(parsed.sum_of_minterms("f"), input);
+        assert_eq!(
+            parsed.product_of_maxterms("f"),
+            "f(x, \"y z\", w) = ΠM(2, 3, 4, 5) · d(1, 7)"
+        );
+        let roundtrip = Parsed::new(&parsed.product_of_maxterms("f"))
+            .unwrap()
+            .into_owned();
+        assert_eq!(roundtrip.minterm_indices(), [0, 6]);
+    }
+
+    #[test]
+    fn karnaugh_map_layout() {
+        let parsed = Parsed::new("f(a, b, c, d) = Σm(0, 2, 5, 7, 8, 10, 13, 15) + d(1)").unwrap();
+        let map = parsed.karnaugh_map().unwrap().with_minimal_groups();
+        assert_eq!((map.num_rows(), map.num_columns()), (4, 4));
+        // Rows and Columns are in Gray-Code Order
+        assert_eq!(map.index(2, 3), 0b1110);
+        assert_eq!(map.cell(0, 1), solver::TruthValue::DontCare);
+        assert_eq!(
+            map.to_string(),
+            "ab \\ cd | 00 01 11 10\n\
+             --------+------------\n\
+             \x20    00 | 1b X  0  1b\n\
+             \x20    01 | 0  1a 1a 0\n\
+             \x20    11 | 0  1a 1a 0\n\
+             \x20    10 | 1b 0  0  1b\n\
+             a: b & d\n\
+             b: !b & !d\n"
+        );
+
+        // Six Variables use the mirrored Layout on both Axes
+        let parsed = Parsed::new("a & !f | b & c & d & e").unwrap();
+        let map = parsed.karnaugh_map().unwrap();
+        assert_eq!((map.num_rows(), map.num_columns()), (8, 8));
+        for row in 0..8 {
+            for column in 0..8 {
+                let index = map.index(row, column);
+                let inputs = parsed.truth_table().row(index).inputs;
+                let expected = parsed.evaluate_slice(&inputs);
+                assert_eq!(map.cell(row, column), solver::TruthValue::from(expected));
+                // Neighbours, also across the Edges, differ in a single Variable
+                let right = map.index(row, (column + 1) % 8);
+                let below = map.index((row + 1) % 8, column);
+                assert_eq!((index ^ right).count_ones(), 1);
+                assert_eq!((index ^ below).count_ones(), 1);
+            }
+        }
+        assert!(Parsed::new("a & b & c & d & e & f & g")
+            .unwrap()
+            .karnaugh_map()
+            .is_none());
+    }
+
+    #[test]
+    fn karnaugh_map_svg() {
+        let parsed = Parsed::new("f(a, b, c, d) = Σm(0, 2, 8, 10, 12, 13, 14, 15)").unwrap();
+        let svg = parsed
+            .karnaugh_map()
+            .unwrap()
+            .with_minimal_groups()
+            .render_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<g ").count(), 2);
+        // !b & !d wraps around all four Corners
+        assert_eq!(svg.matches("<rect").count(), 16 + 4 + 1);
+        assert!(svg.contains("<title>!b &amp; !d</title>"));
+    }
+
+    #[test]
+    fn simplification_laws() {
+        // Complement, Absorption and Idempotence on whole Subtrees
+        assert_eq!(simplified("(a | b) & !(a | b)"), "false");
+        assert_eq!(simplified("(a ^ b) | !(a ^ b)"), "true");
+        assert_eq!(simplified("a | (a & b)"), "a");
+        assert_eq!(simplified("(a -> b) & ((a -> b) | c)"), "a -> b");
+        assert_eq!(simplified("(a & b & c) | (b & a)"), "b & a");
+        assert_eq!(simplified("(a | b) & c & (a | b)"), "(a | b) & c");
+        assert_eq!(simplified("(a ^ b) <-> (a ^ b)"), "true");
+        assert_eq!(simplified("(a & b) -> !(a & b)"), "!(a & b)");
+        // De Morgan, whichever Direction makes the Expression smaller
+        assert_eq!(simplified("!(!a & b)"), "a | !b");
+        assert_eq!(simplified("!a & !b & !c"), "!(a | b | c)");
+        assert_eq!(simplified("!(a | b)"), "!(a | b)");
+        // Consensus and its Dual
+        assert_eq!(simplified("a & b | !a & c | b & c"), "a & b | !a & c");
+        assert_eq!(
+            simplified("(a | b) & (!a | c) & (b | c | d)"),
+            "(a | b) & (!a | c)"
+        );
+        assert_eq!(simplified("!!(!!a & b) | (a & b)"), "a & b");
+        assert_eq!(simplified("!a ^ !b"), "a ^ b");
+    }
+
+    #[test]
+    fn simplification_keeps_random_functions() {
+        use parser::simplifier::size;
+        let mut rng = fastrand::Rng::with_seed(0x2545_F491_4F6C_DD1D);
+        fn random_tree(depth: usize, rng: &mut fastrand::Rng) -> ASTNode {
+            match (depth, rng.usize(..8)) {
+                (0, _) | (_, 0) => match rng.usize(..10) {
+                    0 => ASTNode::Literal(rng.bool()),
+                    i => ASTNode::Identifier(i % 3),
+                },
+                (_, 1 | 2) => random_tree(depth - 1, rng).negate(),
+                _ => ASTNode::infix(
+                    INFIX_OPERATORS[rng.usize(..5)],
+                    random_tree(depth - 1, rng),
+                    random_tree(depth - 1, rng),
+                ),
+            }
+        }
+        for _ in 0..2000 {
+            let tree = random_tree(5, &mut rng);
+            let simplified = tree.clone().try_simplify();
+            assert!(size(&simplified) <= size(&tree), "{}", tree);
+            for row in 0..8usize {
+                let values: Vec<bool> = (0..3).map(|i| (row >> i) & 1 == 1).collect();
+                assert_eq!(
+                    tree.evaluate_slice(&values),
+                    simplified.evaluate_slice(&values),
+                    "{} / {}",
+                    tree,
+                    simplified
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn simplification_trace() {
+        let (simplified, steps) = Parsed::new("!!(!!A & B) | (A & B)")
+            .unwrap()
+            .try_simplify_traced();
+        assert_eq!(
+            simplified.render_derivation(&steps),
+            "0. !!(!!A & B) | A & B\n\
+             1. !!(A & B) | A & B      double negation at lhs.operand.operand.lhs\n\
+             2. A & B | A & B          double negation at lhs\n\
+             3. A & B                  idempotence at root\n"
+        );
+        assert_eq!(
+            steps[0].to_string(),
+            "!!0 = 0    double negation at lhs.operand.operand.lhs"
+        );
+        assert_eq!(steps[1].path, vec![0]);
+        assert_eq!(steps[2].law, Law::Idempotence);
+
+        for input in [
+            "!(!a & !b) & (c | true)",
+            "(a -> a) ^ (b & !b)",
+            "a | (a & b) | !!c",
+        ] {
+            let parsed = Parsed::new(input).unwrap();
+            let (simplified, steps) = parsed.clone().try_simplify_traced();
+            assert_eq!(
+                simplified.to_string(),
+                parsed.clone().try_simplify().to_string()
+            );
+            assert_eq!(
+                steps.first().unwrap().before.to_string(),
+                parsed.to_string()
+            );
+            assert_eq!(
+                steps.last().unwrap().after.to_string(),
+                simplified.to_string()
+            );
+            for (step, next) in steps.iter().zip(steps.iter().skip(1)) {
+                assert_eq!(step.after, next.before);
+            }
+        }
+        let (unchanged, steps) = Parsed::new("a & b").unwrap().try_simplify_traced();
+        assert!(steps.is_empty());
+        assert_eq!(unchanged.render_derivation(&steps), "0. a & b\n");
+    }
+
+    #[test]
+    fn negation_normal_form() {
+        let nnf = |s: &str| format!("{:#}", Parsed::new(s).unwrap().to_nnf());
+        assert_eq!(nnf("!(a & !b)"), "!a | b");
+        assert_eq!(nnf("!!a | !(b | !c)"), "a | !b & c");
+        assert_eq!(nnf("a -> b"), "!a | b");
+        assert_eq!(nnf("!(a -> b)"), "a & !b");
+        assert_eq!(nnf("a ^ b"), "a & !b | !a & b");
+        assert_eq!(nnf("!(a <-> b)"), "a & !b | !a & b");
+        assert_eq!(nnf("!(a ^ !b)"), "a & !b | !a & b");
+        assert_eq!(nnf("!true | a"), "false | a");
+
+        for tree in small_trees() {
+            let converted = tree.to_nnf();
+            assert!(converted.is_nnf(), "{}", converted);
+            for row in 0..8usize {
+                let values: Vec<bool> = (0..3).map(|i| (row >> i) & 1 == 1).collect();
+                assert_eq!(
+                    tree.evaluate_slice(&values),
+                    converted.evaluate_slice(&values),
+                    "{} / {}",
+                    tree,
+                    converted
+                );
+            }
+        }
+        let a = || ASTNode::Identifier(0);
+        let implication = ASTNode::infix(InfixOperator::Implication, a(), a());
+        assert!(!implication.is_nnf());
+        assert!(implication.to_nnf().is_nnf());
+    }
+
+    #[test]
+    fn conjunctive_and_disjunctive_normal_form() {
+        let options = NormalFormOptions::new();
+        let parsed = Parsed::new("(a -> b) & !(c ^ a)").unwrap();
+        assert_eq!(
+            format!("{:#}", parsed.to_cnf_expression(options).unwrap()),
+            "(!a | b) & (!a | c) & (a | !c)"
+        );
+        assert_eq!(
+            format!("{:#}", parsed.to_dnf_expression(options).unwrap()),
+            "!a & !c | a & b & c"
+        );
+        let canonical = options.with_canonical(true);
+        assert_eq!(
+            format!("{:#}", parsed.to_dnf_expression(canonical).unwrap()),
+            "!a & !b & !c | !a & b & !c | a & b & c"
+        );
+        let cnf = parsed.to_cnf(canonical).unwrap();
+        assert_eq!(cnf.clauses.len(), 5);
+        assert!(cnf.clauses.iter().all(|clause| clause.literals.len() == 3));
+        assert_eq!(
+            Parsed::new("a | !a").unwrap().to_cnf(options).unwrap(),
+            Cnf { clauses: vec![] }
+        );
+        assert_eq!(
+            parsed.to_dnf(options).unwrap().cubes[0].literals,
+            vec![Literal::new(0, true), Literal::new(2, true)]
+        );
+
+        for tree in small_trees() {
+            for converted in [
+                tree.to_cnf(options).unwrap().to_ast(),
+                tree.to_dnf(options).unwrap().to_ast(),
+                tree.to_cnf(canonical).unwrap().to_ast(),
+                tree.to_dnf(canonical).unwrap().to_ast(),
+            ] {
+                for row in 0..8usize {
+                    let values: Vec<bool> = (0..3).map(|i| (row >> i) & 1 == 1).collect();
+                    assert_eq!(
+                        tree.evaluate_slice(&values),
+                        converted.evaluate_slice(&values),
+                        "{} / {}",
+                        tree,
+                        converted
+                    );
+                }
+            }
+        }
+
+        // The CNF of n Conjunctions joined by Or has 2^n Clauses
+        let pairs: Vec<String> = (0..8).map(|i| format!("x{} & y{}", i, i)).collect();
+        let joined = pairs.join(" | ");
+        let parsed = Parsed::new(&joined).unwrap();
+        assert_eq!(parsed.to_dnf(options).unwrap().cubes.len(), 8);
+        assert_eq!(parsed.to_cnf(options).unwrap().clauses.len(), 256);
+        let err = parsed.to_cnf(options.with_max_terms(100)).unwrap_err();
+        assert_eq!(err, NormalFormError::TooManyTerms(100));
+        assert_eq!(err.to_string(), "normal form needs more than 100 terms");
+
+        // Xor doubles its Operands in the Negation-Normal-Form, the Limit has to apply first
+        let variables: Vec<String> = (0..40).map(|i| format!("v{}", i)).collect();
+        let chain = variables.join(" ^ ");
+        let parsed = Parsed::new(&chain).unwrap();
+        let start = std::time::Instant::now();
+        let err = parsed.to_cnf(options.with_max_terms(10)).unwrap_err();
+        assert_eq!(err, NormalFormError::TooManyTerms(10));
+        assert!(start.elapsed().as_secs() < 1);
+        let chain = variables[..6].join(" <-> ");
+        let parsed = Parsed::new(&chain).unwrap();
+        assert_eq!(parsed.to_dnf(options).unwrap().cubes.len(), 32);
+    }
+
+    #[test]
+    fn tseitin_encoding() {
+        let parsed = Parsed::new("_t2 & (a | !b) ^ true").unwrap();
+        let encoded = parsed.tseitin(Encoding::Tseitin);
+        let names: Vec<&str> = encoded.parsed().variable_names().collect();
+        assert_eq!(names, ["_t2", "a", "b", "_t1", "_t3", "_t4", "_t5"]);
+        assert_eq!(encoded.num_original_variables(), 3);
+        assert_eq!(encoded.clauses().last().unwrap().literals.len(), 1);
+
+        let mut expressions: Vec<ASTNode> = small_trees();
+        expressions.push(
+            Parsed::new("!(a -> false) <-> (b | !c)")
+                .unwrap()
+                .expression()
+                .clone(),
+        );
+        for tree in expressions {
+            let parsed = Parsed::new("a & b & c").unwrap().with_expression(tree);
+            for encoding in [Encoding::Tseitin, Encoding::PlaistedGreenbaum] {
+                let encoded = parsed.tseitin(encoding);
+                let n = encoded.num_variables();
+                let mut satisfied = vec![false; 8];
+                for row in 0..(1usize << n) {
+                    let model: Vec<bool> = (0..n).map(|i| (row >> i) & 1 == 1).collect();
+                    if encoded.cnf().evaluate(&model) {
+                        // Every Model of the Clauses is one of the Expression
+                        assert!(
+                            parsed.expression().evaluate_slice(&model[..3]),
+                            "{}",
+                            parsed
+                        );
+                        satisfied[row & 7] = true;
+                    }
+                }
+                // and every Model of the Expression extends to one of the Clauses
+                for (row, satisfied) in satisfied.into_iter().enumerate() {
+                    let values: Vec<bool> = (0..3).map(|i| (row >> i) & 1 == 1).collect();
+                    assert_eq!(parsed.expression().evaluate_slice(&values), satisfied);
+                }
+            }
+        }
+
+        let encoded = Parsed::new("x -> y").unwrap().tseitin(Encoding::default());
+        let model = [true, true, true];
+        assert!(encoded.cnf().evaluate(&model));
+        assert_eq!(encoded.original_assignment(&model).to_string(), "1, 1");
+    }
+
+    #[test]
+    fn dimacs_roundtrip() {
+        let parsed = Parsed::new("(a | !b) & (b | \"c d\") & !a").unwrap();
+        let dimacs = parsed.to_dimacs();
+        assert_eq!(
+            dimacs,
+            "c var 1 a\n\
+             c var 2 b\n\
+             c var 3 c d\n\
+             p cnf 3 3\n\
+             1 -2 0\n\
+             2 3 0\n\
+             -1 0\n"
+        );
+        assert_eq!(
+            format!("{:#}", Parsed::from_dimacs(&dimacs).unwrap()),
+            format!("{:#}", parsed)
+        );
+
+        // Other Expressions are written as their Encoding
+        let dimacs = Parsed::new("a ^ b").unwrap().to_dimacs();
+        assert!(dimacs.starts_with("c var 1 a\nc var 2 b\nc var 3 _t1\np cnf 3 3\n"));
+        for s in ["true", "false"] {
+            let dimacs = Parsed::new(s).unwrap().to_dimacs();
+            assert_eq!(format!("{:#}", Parsed::from_dimacs(&dimacs).unwrap()), s);
+        }
+
+        // Benchmark-Style Input: Clauses across Lines, no Names, trailing `%`
+        let parsed = Parsed::from_dimacs("c uf3\np cnf 3 2\n 1 -2\n 3 0 -1 2 0\n%\n0\n").unwrap();
+        assert_eq!(format!("{:#}", parsed), "(x1 | !x2 | x3) & (!x1 | x2)");
+
+        // Names that need Quotes in the Comments
+        let input = "(\"a b\" | \" c\") & (!\"x\ny\" | `\"q\\\"` | \"tail \")";
+        let parsed = Parsed::new(input).unwrap();
+        let dimacs = parsed.to_dimacs();
+        assert!(dimacs.starts_with("c var 1 a b\nc var 2 \" c\"\nc var 3 \"x\\ny\"\n"));
+        let read = Parsed::from_dimacs(&dimacs).unwrap();
+        let names: Vec<&str> = read.variable_names().take(5).collect();
+        assert_eq!(names, ["a b", " c", "x\ny", "\"q\\\"", "tail "]);
+        assert!(read.equivalent(&parsed).holds());
+        let err = Parsed::from_dimacs("c var 1 \"a\np cnf 1 0\n").unwrap_err();
+        assert_eq!(err.expected(), ["quoted name"]);
+
+        let err = Parsed::from_dimacs("1 2 0\n").unwrap_err();
+        assert_eq!(err.to_string(), "unexpected `1`, expected `p cnf` header");
+        let err = Parsed::from_dimacs("p cnf 2\n1 0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unexpected end of line, expected number of clauses"
+        );
+        let err = Parsed::from_dimacs("p cnf 2 1\n1 3 0\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+        assert_eq!(err.expected(), ["variable between 1 and 2"]);
+
+        let err = Parsed::from_dimacs("p cnf 18446744073709551615 0\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 7));
+        assert_eq!(err.expected(), ["number of variables up to 1048576"]);
+        let err = Parsed::from_dimacs("c var 1 a\nc var 2 a\np cnf 2 0\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unexpected `a`, expected unique variable name"
+        );
+        assert_eq!((err.line(), err.column()), (2, 9));
+        let err = Parsed::from_dimacs("c var 1 x2\np cnf 2 0\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 9));
+        let err = Parsed::from_dimacs("c var 1 a\nc var 1 b\np cnf 1 0\n").unwrap_err();
+        assert_eq!(err.expected(), ["variable without a name"]);
+        assert_eq!((err.line(), err.column()), (2, 7));
+    }
+
+    #[test]
+    fn cdcl_solver() {
+        use solver::sat::{self, SatResult};
+
+        let parsed = Parsed::new("(a -> b) & (b -> c) & a & !(c & d)").unwrap();
+        assert_eq!(
+            parsed.solve(),
+            SatResult::Sat(vec![true, true, true, false])
+        );
+        assert_eq!(
+            Parsed::new("(a ^ b) & (a <-> b)").unwrap().solve(),
+            SatResult::Unsat
+        );
+        for tree in small_trees() {
+            let parsed = Parsed::new("a & b & c").unwrap().with_expression(tree);
+            match parsed.solve() {
+                SatResult::Sat(model) => assert!(parsed.expression().evaluate_slice(&model)),
+                SatResult::Unsat => assert_eq!(parsed.truth_table().true_rows().count(), 0),
+            }
+        }
+
+        // Every Pigeon needs a Hole, no Hole takes two Pigeons
+        let (pigeons, holes) = (6, 5);
+        let sits = |pigeon: usize, hole: usize, negated: bool| {
+            Literal::new(pigeon * holes + hole, negated)
+        };
+        let mut clauses: Vec<Clause> = (0..pigeons)
+            .map(|p| Clause {
+                literals: (0..holes).map(|h| sits(p, h, false)).collect(),
+            })
+            .collect();
+        for h in 0..holes {
+            for p in 0..pigeons {
+                for q in (p + 1)..pigeons {
+                    clauses.push(Clause {
+                        literals: vec![sits(p, h, true), sits(q, h, true)],
+                    });
+                }
+            }
+        }
+        let cnf = Cnf { clauses };
+        assert_eq!(sat::solve(&cnf, pigeons * holes), SatResult::Unsat);
+        // With a Pigeon less, every Pigeon gets its own Hole
+        let mut solver = sat::Solver::new(0);
+        for clause in cnf.clauses.iter() {
+            if clause
+                .literals
+                .iter()
+                .all(|l| l.variable < (pigeons - 1) * holes)
+            {
+                solver.add_clause(clause);
+            }
+        }
+        match solver.solve() {
+            SatResult::Sat(model) => assert!(cnf.clauses[..pigeons - 1]
+                .iter()
+                .all(|c| c.evaluate(&model))),
+            SatResult::Unsat => panic!("{} pigeons fit into {} holes", pigeons - 1, holes),
+        }
+    }
+
+    #[test]
+    fn tautology_and_equivalence_checks() {
+        let parsed = |s: &'static str| Parsed::new(s).unwrap();
+        assert!(parsed("(a -> b) | (b -> a)").is_tautology().holds());
+        assert!(parsed("a & !a | false").is_contradiction().holds());
+        let satisfiability = parsed("a ^ b").is_satisfiable();
+        assert!(satisfiability.is_satisfiable());
+        let assignment = satisfiability.assignment().unwrap();
+        assert_ne!(assignment.get("a"), assignment.get("b"));
+        assert_eq!(
+            parsed("a & !a").is_satisfiable(),
+            Satisfiability::Unsatisfiable
+        );
+
+        let verdict = parsed("a -> b").is_tautology();
+        let counterexample = verdict.counterexample().unwrap();
+        assert_eq!(counterexample.to_string(), "a = 1, b = 0");
+        let verdict = parsed("a | b").is_contradiction();
+        let counterexample = verdict.counterexample().unwrap();
+        assert!(counterexample.get("a").unwrap() || counterexample.get("b").unwrap());
+
+        // The Variables are numbered differently in both Expressions
+        let lhs = parsed("!(x & y) | z");
+        assert!(lhs.equivalent(&parsed("z | !y | !x")).holds());
+        assert!(lhs.equivalent(&parsed("x -> (y -> z)")).holds());
+        let verdict = lhs.equivalent(&parsed("y -> z"));
+        let counterexample = verdict.counterexample().unwrap();
+        assert_eq!(counterexample.to_string(), "x = 0, y = 1, z = 0");
+
+        assert!(parsed("x & y").implies(&parsed("y | w")).holds());
+        let verdict = parsed("y | w").implies(&parsed("x & y"));
+        let counterexample = verdict.counterexample().unwrap();
+        let names: Vec<&str> = counterexample
+            .values
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        assert_eq!(names, ["y", "w", "x"]);
+        assert!(counterexample.get("y").unwrap() || counterexample.get("w").unwrap());
+        assert!(!(counterexample.get("x").unwrap() && counterexample.get("y").unwrap()));
+    }
 }
@@ -2,10 +2,7 @@
 
 /* --------------------------------------------------------------------------------------------- */
 
-mod parser;
-use parser::ast_node::ASTNode;
-use parser::operators::{InfixOperator, UnaryOperator};
-use parser::parsing::Parsed;
+use logic_parser::{ASTNode, InfixOperator, Parsed, UnaryOperator};
 
 /* --------------------------------------------------------------------------------------------- */
 
@@ -28,7 +25,7 @@ fn main() {
             println!("{:?}", parsed);
             println!("{:#?}", parsed);
         }
-        Err(s) => print!("{}", s),
+        Err(e) => print!("{}", e.render()),
     }
 }
 
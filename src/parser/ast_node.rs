@@ -2,13 +2,17 @@
 
 /* --------------------------------------------------------------------------------------------- */
 
+use crate::parser::error::NormalFormError;
+use crate::parser::normal_form::{self, Cnf, Dnf, NormalFormOptions};
 use crate::parser::operators::{InfixOperator, UnaryOperator};
+use crate::parser::printer::Printer;
+use crate::parser::simplifier::{self, RewriteStep};
 
 use std::fmt::{Binary, Debug, Display, Formatter};
 
 /* --------------------------------------------------------------------------------------------- */
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ASTNode {
     InfixOperation {
         op: InfixOperator,
@@ -29,13 +33,24 @@ impl ASTNode {
     pub fn negate(self) -> Self {
         match self {
             ASTNode::Literal(v) => ASTNode::Literal(!v),
-            ASTNode::UnaryOperation { op, expr } if op == UnaryOperator::Not => *expr,
+            ASTNode::UnaryOperation {
+                op: UnaryOperator::Not,
+                expr,
+            } => *expr,
             expr => ASTNode::UnaryOperation {
                 op: UnaryOperator::Not,
                 expr: Box::new(expr),
             },
         }
     }
+    /// The Operation `lhs op rhs`.
+    pub fn infix(op: InfixOperator, lhs: Self, rhs: Self) -> Self {
+        ASTNode::InfixOperation {
+            op,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        }
+    }
     pub fn evaluate(&self, binding: &Vec<(usize, bool)>) -> bool {
         match self {
             Self::Literal(l) => *l,
@@ -47,55 +62,86 @@ impl ASTNode {
             _ => unreachable!(),
         }
     }
-    pub fn try_simplify(self) -> Self {
+    /// Evaluates the Expression with the Values indexed by Integer-Identifier.
+    pub fn evaluate_slice(&self, values: &[bool]) -> bool {
         match self {
-            Self::UnaryOperation { op, expr } => match expr.try_simplify() {
-                Self::Literal(l) => Self::Literal(op.apply(l)),
-                // Remove double Negations
-                Self::UnaryOperation { op: op2, expr }
-                    if (op == UnaryOperator::Not) && (op2 == UnaryOperator::Not) =>
-                {
-                    *expr
-                }
-                e => Self::UnaryOperation {
-                    op,
-                    expr: Box::new(e),
-                },
-            },
+            Self::Literal(l) => *l,
+            Self::Identifier(i) => values[*i],
+            Self::UnaryOperation { op, expr } => op.apply(expr.evaluate_slice(values)),
+            Self::InfixOperation { op, lhs, rhs } => {
+                op.apply(lhs.evaluate_slice(values), rhs.evaluate_slice(values))
+            }
+            _ => unreachable!(),
+        }
+    }
+    /// Evaluates the Expression, looking up every Variable with `value`.
+    /// Returns the Integer-Identifier of the first Variable without a Value.
+    pub fn try_evaluate<F>(&self, value: &F) -> Result<bool, usize>
+    where
+        F: Fn(usize) -> Option<bool>,
+    {
+        match self {
+            Self::Literal(l) => Ok(*l),
+            Self::Identifier(i) => value(*i).ok_or(*i),
+            Self::UnaryOperation { op, expr } => Ok(op.apply(expr.try_evaluate(value)?)),
             Self::InfixOperation { op, lhs, rhs } => {
-                op.simplify(lhs.try_simplify(), rhs.try_simplify())
+                Ok(op.apply(lhs.try_evaluate(value)?, rhs.try_evaluate(value)?))
             }
-            _ => self,
+            _ => unreachable!(),
+        }
+    }
+    /// Simplifies the Expression with the Laws of Boolean Algebra, see `simplifier::Law`.
+    /// The Result is equivalent to the Expression and never larger.
+    pub fn try_simplify(self) -> Self {
+        simplifier::simplify(self)
+    }
+    /// Like `try_simplify`, but also returns every Rewrite that led to the Result, in Order.
+    pub fn try_simplify_traced(self) -> (Self, Vec<RewriteStep>) {
+        simplifier::simplify_traced(self)
+    }
+    /// Equivalent Expression in Negation-Normal-Form: Negations only apply to Variables, and
+    /// Implication, Xor and Equivalence are rewritten with And, Or and Not. Xor and
+    /// Equivalence duplicate their Operands, so nested Chains of them grow exponentially.
+    pub fn to_nnf(&self) -> Self {
+        normal_form::nnf(self, false)
+    }
+    /// Checks if the Expression is in Negation-Normal-Form, see `to_nnf`.
+    pub fn is_nnf(&self) -> bool {
+        normal_form::is_nnf(self)
+    }
+    /// Conjunctive Normal Form of the Expression, found by distributing Or over And. Canonical
+    /// Forms range over the Variables up to the largest Integer-Identifier in the Expression.
+    pub fn to_cnf(&self, options: NormalFormOptions) -> Result<Cnf, NormalFormError> {
+        normal_form::cnf(self, self.num_variables(), options)
+    }
+    /// Disjunctive Normal Form of the Expression, found by distributing And over Or. Canonical
+    /// Forms range over the Variables up to the largest Integer-Identifier in the Expression.
+    pub fn to_dnf(&self, options: NormalFormOptions) -> Result<Dnf, NormalFormError> {
+        normal_form::dnf(self, self.num_variables(), options)
+    }
+    /// One more than the largest Integer-Identifier in the Expression.
+    pub(crate) fn num_variables(&self) -> usize {
+        match self {
+            ASTNode::Identifier(i) => i + 1,
+            ASTNode::UnaryOperation { expr, .. } => expr.num_variables(),
+            ASTNode::InfixOperation { lhs, rhs, .. } => {
+                lhs.num_variables().max(rhs.num_variables())
+            }
+            _ => 0,
         }
     }
 }
 
 /* --------------------------------------------------------------------------------------------- */
 
-/// Prints the Expression with Braces.
+/// Prints the Expression with only the Braces required by the Operator-Precedence.
 impl Binary for ASTNode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ASTNode::InfixOperation { op, lhs, rhs } => {
-                write!(f, "{:b} {} {:b}", lhs.as_ref(), op, rhs.as_ref())
-            }
-            ASTNode::UnaryOperation { op, expr } => {
-                let inner_expr_is_infix = matches!(**expr, ASTNode::InfixOperation { .. });
-                write!(f, "{:#}", op);
-                if (inner_expr_is_infix) {
-                    write!(f, "({:b})", expr.as_ref())
-                } else {
-                    write!(f, "{:b}", expr.as_ref())
-                }
-            }
-            ASTNode::Identifier(i) => write!(f, "{}", i),
-            ASTNode::Literal(l) => write!(f, "{}", l),
-            _ => unreachable!(),
-        }
+        write!(f, "{}", Printer::new().print_numbered(self))
     }
 }
 
-/// Prints the Expression without Braces.
+/// Prints the Expression with Braces around every Infix-Operation.
 impl Display for ASTNode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -119,42 +165,20 @@ impl Display for ASTNode {
 /* --------------------------------------------------------------------------------------------- */
 
 impl ASTNode {
-    pub fn fmt_binary_names<'a>(
+    /// Print the Expression with only the required Braces using the provided Bindings.
+    pub fn fmt_binary_names<S: AsRef<str>>(
         &self,
-        bindings: &Vec<&'a str>,
+        bindings: &[S],
         f: &mut Formatter<'_>,
     ) -> std::fmt::Result {
-        match self {
-            ASTNode::InfixOperation { op, lhs, rhs } => {
-                write!(f, "(");
-                lhs.fmt_display_names(bindings, f);
-                write!(f, "{:#}", op);
-                rhs.fmt_display_names(bindings, f);
-                write!(f, ")")
-            }
-            ASTNode::UnaryOperation { op, expr } => {
-                let inner_expr_is_infix = matches!(**expr, ASTNode::InfixOperation { .. });
-                write!(f, "{:#}", op);
-                if (inner_expr_is_infix) {
-                    write!(f, "(");
-                }
-                expr.fmt_binary_names(bindings, f);
-                if (inner_expr_is_infix) {
-                    write!(f, ")");
-                }
-                write!(f, "")
-            }
-            ASTNode::Identifier(i) => write!(f, "{:#}", bindings[*i]),
-            ASTNode::Literal(l) => write!(f, "{:#}", l),
-            _ => unreachable!(),
-        }
+        write!(f, "{}", Printer::new().print(self, bindings))
     }
 }
 
 impl ASTNode {
-    pub fn fmt_debug_names<'a>(
+    pub fn fmt_debug_names<S: AsRef<str>>(
         &self,
-        bindings: &Vec<&'a str>,
+        bindings: &[S],
         f: &mut Formatter<'_>,
     ) -> std::fmt::Result {
         match self {
@@ -168,7 +192,7 @@ impl ASTNode {
             ASTNode::UnaryOperation { op, expr } => {
                 write!(f, "{:#?}", op)
             }
-            ASTNode::Identifier(i) => write!(f, "\"{:#}\"", bindings[*i]),
+            ASTNode::Identifier(i) => write!(f, "\"{:#}\"", bindings[*i].as_ref()),
             ASTNode::Literal(l) => write!(f, "{:#}", l),
             _ => unreachable!(),
         }
@@ -178,24 +202,22 @@ impl ASTNode {
 impl ASTNode {
     /// Print the Expression to the specified Formatter using the
     /// provided Bindings from Integer-Identifier to Identifier-Name.
-    pub fn fmt_display_names<'a>(
+    /// The alternate Form only emits the Braces required by the Operator-Precedence.
+    pub fn fmt_display_names<S: AsRef<str>>(
         &self,
-        bindings: &Vec<&'a str>,
+        bindings: &[S],
         f: &mut Formatter<'_>,
     ) -> std::fmt::Result {
+        if (f.alternate()) {
+            return write!(f, "{}", Printer::new().print(self, bindings));
+        }
         match self {
             ASTNode::InfixOperation { op, lhs, rhs } => {
-                if (f.alternate()) {
-                    lhs.fmt_display_names(bindings, f);
-                    write!(f, " {:#} ", op);
-                    rhs.fmt_display_names(bindings, f)
-                } else {
-                    write!(f, "(");
-                    lhs.fmt_display_names(bindings, f);
-                    write!(f, " {:#} ", op);
-                    rhs.fmt_display_names(bindings, f);
-                    write!(f, ")")
-                }
+                write!(f, "(");
+                lhs.fmt_display_names(bindings, f);
+                write!(f, " {:#} ", op);
+                rhs.fmt_display_names(bindings, f);
+                write!(f, ")")
             }
             ASTNode::UnaryOperation { op, expr } => {
                 let inner_expr_is_infix = matches!(**expr, ASTNode::InfixOperation { .. });
@@ -209,7 +231,7 @@ impl ASTNode {
                 }
                 write!(f, "")
             }
-            ASTNode::Identifier(i) => write!(f, "{:#}", bindings[*i]),
+            ASTNode::Identifier(i) => fmt_identifier(bindings[*i].as_ref(), f),
             ASTNode::Literal(l) => write!(f, "{:#}", l),
             _ => unreachable!(),
         }
@@ -217,3 +239,34 @@ impl ASTNode {
 }
 
 /* --------------------------------------------------------------------------------------------- */
+
+/// Words that the Grammar reserves and which therefore can't be used as plain Identifiers.
+const KEYWORDS: [&str; 8] = ["and", "or", "xor", "implies", "iff", "not", "true", "false"];
+
+/// Checks if the Name can be written without Quotes, i.e. if it is a dotted Path of Words
+/// made of ASCII-Letters, Digits and Underscores that don't start with a Digit.
+pub(crate) fn is_plain_identifier(name: &str) -> bool {
+    let is_word = |w: &str| {
+        w.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && w.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    let first = name.split('.').next().unwrap_or("");
+    name.split('.').all(is_word) && !KEYWORDS.iter().any(|k| first.eq_ignore_ascii_case(k))
+}
+
+/// Writes the Identifier-Name so that it parses back to the same Name.
+/// Names that aren't plain Identifiers are quoted, using Backticks if the Name contains '"',
+/// and doubling the '"' if it contains Backticks as well.
+pub(crate) fn fmt_identifier(name: &str, f: &mut Formatter<'_>) -> std::fmt::Result {
+    if is_plain_identifier(name) {
+        write!(f, "{}", name)
+    } else if !name.contains('"') {
+        write!(f, "\"{}\"", name)
+    } else if !name.contains('`') {
+        write!(f, "`{}`", name)
+    } else {
+        write!(f, "\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -5,10 +5,35 @@ use std::ops::{Deref, DerefMut, Index, Not};
 
 /* --------------------------------------------------------------------------------------------- */
 
+/// Order in which `Bindings` enumerates the Assignments of its free Variables.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum BindingOrder {
+    /// Counts in Binary with the last free Variable as the least significant Bit.
+    #[default]
+    Binary,
+    /// Reflected Gray-Code, every Step flips exactly one Variable.
+    Gray,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Assignment of Truth-Values to named Variables that can step through every Assignment.
+///
+/// The Iterator starts at the current Assignment and yields, for every following one, the
+/// Index of the most significant Variable that changed (the only one, in Gray-Code Order).
+/// Together with the starting Assignment it visits each of the 2^n Assignments of the free
+/// Variables exactly once and then stays exhausted until `reset_values` is called.
+/// Fixed Variables keep their Value and are skipped by the Enumeration.
+///
+/// The Steps are counted in a `usize`, so with `usize::BITS` or more free Variables the
+/// Iterator only covers the first `usize::MAX` Assignments (which is endless in practice).
 #[derive(Debug, Clone, Hash)]
 pub struct Bindings<'a> {
     v: Vec<bool>,
     n: Vec<&'a str>,
+    fixed: Vec<bool>,
+    order: BindingOrder,
+    step: usize,
 }
 
 /* --------------------------------------------------------------------------------------------- */
@@ -18,25 +43,70 @@ impl<'a> Bindings<'a> {
         Self {
             v: Vec::new(),
             n: Vec::new(),
+            fixed: Vec::new(),
+            order: BindingOrder::Binary,
+            step: 0,
         }
     }
+    pub fn with_order(mut self, order: BindingOrder) -> Self {
+        self.order = order;
+        self.reset_values();
+        self
+    }
     pub fn push_binding(&mut self, value: bool, name: &'a str) {
         self.n.push(name);
         self.v.push(value);
+        self.fixed.push(false);
     }
     pub fn contains(&self, name: &'a str) -> bool {
         self.n.iter().any(|n| (*n).eq(name))
     }
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.n.iter().position(|n| (*n).eq(name))
+    }
     pub fn num_bindings(&self) -> usize {
         self.v.len()
     }
     pub fn get_name(&self, idx: usize) -> &'a str {
         self.n[idx]
     }
+    /// Number of Assignments the Enumeration visits, i.e. 2^(free Variables), or `None` if
+    /// that doesn't fit into a `usize`.
+    pub fn num_assignments(&self) -> Option<usize> {
+        1usize.checked_shl(self.num_free() as u32)
+    }
+    /// Fixes the Variable to the Value and restarts the Enumeration of the other ones.
+    /// Returns false if there is no Variable with that Name.
+    pub fn fix(&mut self, name: &str, value: bool) -> bool {
+        match self.index_of(name) {
+            Some(idx) => {
+                self.fix_index(idx, value);
+                true
+            }
+            None => false,
+        }
+    }
+    pub fn fix_index(&mut self, idx: usize, value: bool) {
+        self.fixed[idx] = true;
+        self.v[idx] = value;
+        self.reset_values();
+    }
+    /// Releases a fixed Variable and restarts the Enumeration.
+    pub fn unfix_index(&mut self, idx: usize) {
+        self.fixed[idx] = false;
+        self.reset_values();
+    }
+    pub fn is_fixed(&self, idx: usize) -> bool {
+        self.fixed[idx]
+    }
+    /// Sets every free Variable to false and restarts the Enumeration.
     pub fn reset_values(&mut self) {
         for i in 0..self.v.len() {
-            self.v[i] = false;
+            if (!self.fixed[i]) {
+                self.v[i] = false;
+            }
         }
+        self.step = 0;
     }
     pub fn advance(&mut self) -> Option<&Vec<bool>> {
         match self.next() {
@@ -44,6 +114,27 @@ impl<'a> Bindings<'a> {
             None => None,
         }
     }
+    /// Iterator over every Assignment, starting with the current one.
+    pub fn assignments(self) -> Assignments<'a> {
+        Assignments {
+            bindings: self,
+            started: false,
+        }
+    }
+    fn num_free(&self) -> usize {
+        self.fixed.iter().filter(|f| !**f).count()
+    }
+    /// Index of the Variable behind the `bit`-th free Variable, counted from the last one.
+    fn free_index(&self, bit: usize) -> usize {
+        self.fixed
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, f)| !**f)
+            .nth(bit)
+            .unwrap()
+            .0
+    }
 }
 
 /* --------------------------------------------------------------------------------------------- */
@@ -91,17 +182,49 @@ impl<'a> Iterator for Bindings<'a> {
     type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
-        for i in (0..self.v.len()).rev() {
-            if (!self.v[i]) {
-                self.v[i] = !self.v[i];
-                return Some(i);
+        let next_step = self.step.checked_add(1)?;
+        if (self.num_assignments().is_some_and(|n| next_step >= n)) {
+            // Every Assignment was visited, stay exhausted until the Values are reset.
+            return None;
+        }
+        // The lowest set Bit of the Step-Counter is the one that changes in both Orders.
+        let bit = next_step.trailing_zeros() as usize;
+        let idx = self.free_index(bit);
+        match self.order {
+            BindingOrder::Binary => {
+                // Counting up flips every Bit below the lowest set Bit as well.
+                // Flipping (instead of setting) also works for non-zero starting Values.
+                for lower in 0..=bit {
+                    let lower_idx = self.free_index(lower);
+                    self.v[lower_idx] = !self.v[lower_idx];
+                }
             }
-            self.v[i] = !self.v[i];
+            BindingOrder::Gray => self.v[idx] = !self.v[idx],
+        }
+        self.step = next_step;
+        Some(idx)
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Iterator over every Assignment of some `Bindings`, see `Bindings::assignments`.
+#[derive(Debug, Clone)]
+pub struct Assignments<'a> {
+    bindings: Bindings<'a>,
+    started: bool,
+}
+
+impl<'a> Iterator for Assignments<'a> {
+    type Item = Vec<bool>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if (self.started) {
+            self.bindings.advance().cloned()
+        } else {
+            self.started = true;
+            Some(self.bindings.v.clone())
         }
-        // All Truth-Values are reset to false here
-        // Basically the Iterator is exhausted here but it can be automatically resumed.
-        // This Iterator doesn't return the all false Vec at the next Call though.
-        None
     }
 }
 
@@ -109,13 +232,8 @@ impl<'a> Iterator for Bindings<'a> {
 
 impl<'a> Display for Bindings<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut buff = String::with_capacity((self.v.len() * 3) - 2);
-        for i in 0..(self.v.len() - 1) {
-            buff.push(if self.v[i] { '1' } else { '0' });
-            buff.push(','); buff.push(' ');
-        }
-        buff.push(if self.v[self.v.len() - 1] { '1' } else { '0' });
-        write!(f, "{}", buff)
+        let values: Vec<&str> = self.v.iter().map(|v| if *v { "1" } else { "0" }).collect();
+        write!(f, "{}", values.join(", "))
     }
 }
 
@@ -124,8 +242,14 @@ impl<'a> Display for Bindings<'a> {
 impl<'a> From<Vec<&'a str>> for Bindings<'a> {
     fn from(v: Vec<&'a str>) -> Self {
         let values = vec![false; v.len()];
-        let expired = v.is_empty();
-        Self { v: values, n: v }
+        let fixed = vec![false; v.len()];
+        Self {
+            v: values,
+            n: v,
+            fixed,
+            order: BindingOrder::Binary,
+            step: 0,
+        }
     }
 }
 
@@ -133,31 +257,24 @@ impl<'a> From<Vec<(bool, &'a str)>> for Bindings<'a> {
     fn from(v: Vec<(bool, &'a str)>) -> Self {
         let mut values = Vec::with_capacity(v.len());
         let mut names = Vec::with_capacity(v.len());
-        let expired = v.is_empty();
         for (v, n) in v {
             values.push(v);
             names.push(n);
         }
+        let fixed = vec![false; values.len()];
         Self {
             v: values,
             n: names,
+            fixed,
+            order: BindingOrder::Binary,
+            step: 0,
         }
     }
 }
 
 impl<'a> From<Vec<(&'a str, bool)>> for Bindings<'a> {
     fn from(v: Vec<(&'a str, bool)>) -> Self {
-        let mut values = Vec::with_capacity(v.len());
-        let mut names = Vec::with_capacity(v.len());
-        let expired = v.is_empty();
-        for (n, v) in v {
-            values.push(v);
-            names.push(n);
-        }
-        Self {
-            v: values,
-            n: names,
-        }
+        Self::from(v.into_iter().map(|(n, v)| (v, n)).collect::<Vec<_>>())
     }
 }
 
@@ -0,0 +1,94 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Spelling-Family used for the Operators and Constants of an Input.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Dialect {
+    /// `& | ^ -> <-> !`
+    #[default]
+    Ascii,
+    /// `AND OR XOR IMPLIES IFF NOT`
+    Word,
+    /// `∧ ∨ ⊕ → ↔ ¬ ⊤ ⊥`
+    Unicode,
+    /// `\land \lor \oplus \to \leftrightarrow \neg \top \bot`
+    Latex,
+    /// The Input used Spellings from more than one Dialect.
+    Mixed,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Dialect {
+    /// Classifies the Spelling of a single Operator- or Literal-Token.
+    /// Returns `None` for Tokens every Dialect shares (`true` and `false`).
+    pub(crate) fn of_token(token: &str) -> Option<Dialect> {
+        if token.starts_with('\\') {
+            Some(Dialect::Latex)
+        } else if !token.is_ascii() {
+            Some(Dialect::Unicode)
+        } else if token.eq_ignore_ascii_case("true") || token.eq_ignore_ascii_case("false") {
+            None
+        } else if token.chars().all(|c| c.is_ascii_alphabetic()) {
+            Some(Dialect::Word)
+        } else {
+            Some(Dialect::Ascii)
+        }
+    }
+    /// Combines the Dialects of two Parts of the same Input.
+    pub(crate) fn combine(self, other: Dialect) -> Dialect {
+        if self == other {
+            self
+        } else {
+            Dialect::Mixed
+        }
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Spelling-Family used when printing an Expression, see `Parsed::display_with`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Notation {
+    /// `& | ^ -> <-> ! true false`
+    #[default]
+    Ascii,
+    /// `∧ ∨ ⊕ → ↔ ¬ ⊤ ⊥`
+    Unicode,
+    /// `AND OR XOR IMPLIES IFF NOT TRUE FALSE`
+    Word,
+    /// `&& || != == ! true false`, Implications are written as `!a || b`.
+    CStyle,
+    /// `\land \lor \oplus \to \leftrightarrow \neg \top \bot`
+    Latex,
+}
+
+impl Notation {
+    pub fn literal(&self, value: bool) -> &'static str {
+        match (self, value) {
+            (Notation::Ascii | Notation::CStyle, true) => "true",
+            (Notation::Ascii | Notation::CStyle, false) => "false",
+            (Notation::Unicode, true) => "⊤",
+            (Notation::Unicode, false) => "⊥",
+            (Notation::Word, true) => "TRUE",
+            (Notation::Word, false) => "FALSE",
+            (Notation::Latex, true) => "\\top",
+            (Notation::Latex, false) => "\\bot",
+        }
+    }
+}
+
+/// Prints in the Dialect of the Input, mixed Inputs fall back to ASCII.
+impl From<Dialect> for Notation {
+    fn from(d: Dialect) -> Self {
+        match d {
+            Dialect::Ascii | Dialect::Mixed => Notation::Ascii,
+            Dialect::Word => Notation::Word,
+            Dialect::Unicode => Notation::Unicode,
+            Dialect::Latex => Notation::Latex,
+        }
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -0,0 +1,244 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::{
+    error::ParseError,
+    normal_form::{Clause, Cnf, Literal},
+};
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Largest Number of Variables `read` accepts, so that a Header can't request an arbitrary
+/// Amount of Memory for the Names.
+pub(crate) const MAX_VARIABLES: usize = 1 << 20;
+
+/// Writes the Clauses in the DIMACS-CNF Format. The Variables are numbered from 1 in the
+/// Order of the Names, which are listed in "c var <number> <name>" Comments before the Header.
+/// Names that wouldn't survive as the Rest of a Line are quoted, see `quote`.
+pub(crate) fn write<S: AsRef<str>>(cnf: &Cnf, names: &[S]) -> String {
+    let mut out = String::new();
+    for (idx, name) in names.iter().enumerate() {
+        out.push_str(&format!("c var {} {}\n", idx + 1, quote(name.as_ref())));
+    }
+    out.push_str(&format!("p cnf {} {}\n", names.len(), cnf.clauses.len()));
+    for clause in cnf.clauses.iter() {
+        for literal in clause.literals.iter() {
+            out.push_str(&format!("{} ", literal));
+        }
+        out.push_str("0\n");
+    }
+    out
+}
+
+/// Reads Clauses in the DIMACS-CNF Format, together with the Names of the Variables.
+///
+/// Names come from "c var <number> <name>" Comments, Variables without one are called
+/// "x<number>". The Name is the whole Rest of the Line, unless it is quoted like `write` does. Clauses may span Lines, the `0` ending the last one may be missing, and a Line
+/// starting with `%` ends the Input like in the Benchmarks of the SAT-Competition. The Number
+/// of Clauses in the Header isn't checked, the Number of Variables may be at most
+/// `MAX_VARIABLES`. Every Variable and every Name may only occur in one Comment.
+pub(crate) fn read(source: &str) -> Result<(Cnf, Vec<String>), ParseError> {
+    let mut names: Option<Vec<Option<String>>> = None;
+    let mut comments: Vec<(usize, usize, usize, String)> = Vec::new();
+    let mut clauses: Vec<Clause> = Vec::new();
+    let mut clause: Vec<Literal> = Vec::new();
+
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let content = line.trim_end_matches(['\n', '\r']);
+        let tokens = tokens(content, start);
+        match tokens.first() {
+            None => continue,
+            Some((_, "%")) => break,
+            Some((_, "c")) => {
+                if let [_, (_, "var"), (pos, number), (name_start, _), ..] = tokens.as_slice() {
+                    if let Ok(number) = number.parse::<usize>() {
+                        let name = unquote(&content[(name_start - start)..]).ok_or_else(|| {
+                            ParseError::at(source, *name_start, expected("quoted name"))
+                        })?;
+                        comments.push((*pos, number, *name_start, name));
+                    }
+                }
+            }
+            Some((pos, "p")) => {
+                if (names.is_some()) {
+                    return Err(ParseError::at(source, *pos, expected("clause")));
+                }
+                // Missing Parts are reported at the End of the Line
+                let token = |idx: usize| {
+                    tokens
+                        .get(idx)
+                        .copied()
+                        .unwrap_or((start + content.len(), ""))
+                };
+                let count = |idx: usize, what: &str| -> Result<usize, ParseError> {
+                    let (pos, token) = token(idx);
+                    token
+                        .parse()
+                        .map_err(|_| ParseError::at(source, pos, expected(what)))
+                };
+                if (token(1).1 != "cnf") {
+                    return Err(ParseError::at(source, token(1).0, expected("`cnf`")));
+                }
+                let num_variables = count(2, "number of variables")?;
+                if (num_variables > MAX_VARIABLES) {
+                    let limit = format!("number of variables up to {}", MAX_VARIABLES);
+                    return Err(ParseError::at(source, token(2).0, vec![limit]));
+                }
+                count(3, "number of clauses")?;
+                if let Some((pos, _)) = tokens.get(4) {
+                    return Err(ParseError::at(source, *pos, expected("end of line")));
+                }
+                names = Some(vec![None; num_variables]);
+            }
+            Some((pos, _)) => {
+                let Some(names) = names.as_ref() else {
+                    return Err(ParseError::at(source, *pos, expected("`p cnf` header")));
+                };
+                for (pos, token) in tokens {
+                    let value: i64 = token
+                        .parse()
+                        .map_err(|_| ParseError::at(source, pos, expected("literal")))?;
+                    let variable = value.unsigned_abs() as usize;
+                    if (value == 0) {
+                        clauses.push(Clause {
+                            literals: std::mem::take(&mut clause),
+                        });
+                    } else if (variable <= names.len()) {
+                        clause.push(Literal::new(variable - 1, value < 0));
+                    } else {
+                        let variables = format!("variable between 1 and {}", names.len());
+                        return Err(ParseError::at(source, pos, vec![variables]));
+                    }
+                }
+            }
+        }
+    }
+    let Some(mut names) = names else {
+        return Err(ParseError::at(
+            source,
+            source.len(),
+            expected("`p cnf` header"),
+        ));
+    };
+    if (!clause.is_empty()) {
+        clauses.push(Clause { literals: clause });
+    }
+    for (pos, number, _, name) in comments.iter() {
+        if (*number == 0 || *number > names.len()) {
+            let variables = format!("variable between 1 and {}", names.len());
+            return Err(ParseError::at(source, *pos, vec![variables]));
+        }
+        if (names[number - 1].is_some()) {
+            return Err(ParseError::at(
+                source,
+                *pos,
+                expected("variable without a name"),
+            ));
+        }
+        names[number - 1] = Some(name.clone());
+    }
+    let names: Vec<String> = names
+        .into_iter()
+        .enumerate()
+        .map(|(idx, name)| name.unwrap_or_else(|| format!("x{}", idx + 1)))
+        .collect();
+    // Also catches Names like "x2" that clash with the Default of an unnamed Variable
+    let mut seen = HashSet::new();
+    let duplicate = names.iter().find(|name| !seen.insert(name.as_str()));
+    if let Some(duplicate) = duplicate {
+        let pos = comments
+            .iter()
+            .rev()
+            .find(|(_, _, _, name)| name == duplicate)
+            .map_or(0, |(_, _, name_start, _)| *name_start);
+        return Err(ParseError::at(
+            source,
+            pos,
+            expected("unique variable name"),
+        ));
+    }
+    Ok((Cnf { clauses }, names))
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// The Name as it is written after "c var <number>": unchanged, unless it would be empty,
+/// change when the Line is trimmed, span Lines or look quoted. Then it is put in '"' with '"',
+/// '\\', Line-Feeds and Carriage-Returns escaped by a Backslash.
+fn quote(name: &str) -> Cow<'_, str> {
+    let plain = !name.is_empty()
+        && name.trim() == name
+        && !name.starts_with('"')
+        && !name.contains(['\n', '\r']);
+    if (plain) {
+        return Cow::Borrowed(name);
+    }
+    let mut quoted = String::from("\"");
+    for c in name.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    Cow::Owned(quoted)
+}
+
+/// Reverses `quote`, None if the Name starts with '"' but isn't quoted properly.
+fn unquote(name: &str) -> Option<String> {
+    if (!name.starts_with('"')) {
+        return Some(name.to_string());
+    }
+    let inner = name.trim_end().strip_prefix('"')?.strip_suffix('"')?;
+    let mut unquoted = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.push(match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                c @ ('"' | '\\') => c,
+                _ => return None,
+            }),
+            '"' => return None,
+            c => unquoted.push(c),
+        }
+    }
+    Some(unquoted)
+}
+
+/// Whitespace-separated Tokens of the Line with their Byte-Position in the Input.
+fn tokens(line: &str, start: usize) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut token_start = None;
+    for (idx, c) in line
+        .char_indices()
+        .chain(std::iter::once((line.len(), ' ')))
+    {
+        match (c.is_whitespace(), token_start) {
+            (true, Some(from)) => {
+                tokens.push((start + from, &line[from..idx]));
+                token_start = None;
+            }
+            (false, None) => token_start = Some(idx),
+            _ => {}
+        }
+    }
+    tokens
+}
+
+fn expected(what: &str) -> Vec<String> {
+    vec![what.to_string()]
+}
+
+/* --------------------------------------------------------------------------------------------- */
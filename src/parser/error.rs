@@ -0,0 +1,221 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Error returned when an Input can't be parsed.
+///
+/// Spans are Byte-Offsets into the original Input, Lines and Columns are 1-based and count
+/// Characters so they can be handed to an Editor directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A Token was found where none of the expected Tokens could start.
+    UnexpectedToken {
+        span: Range<usize>,
+        line: usize,
+        column: usize,
+        expected: Vec<String>,
+        found: String,
+        source_line: String,
+    },
+    /// The Input ended while more Tokens were expected.
+    UnexpectedEndOfInput {
+        span: Range<usize>,
+        line: usize,
+        column: usize,
+        expected: Vec<String>,
+        source_line: String,
+    },
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl ParseError {
+    /// Creates the Error for the Byte-Position `pos` in `source`.
+    /// The offending Token is the Word (or single Symbol) starting at that Position.
+    pub(crate) fn at(source: &str, pos: usize, expected: Vec<String>) -> Self {
+        let line_start = source[..pos].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[pos..].find('\n').map_or(source.len(), |i| pos + i);
+        let line = source[..pos].matches('\n').count() + 1;
+        let column = source[line_start..pos].chars().count() + 1;
+        let source_line = source[line_start..line_end].to_string();
+
+        let rest = &source[pos..line_end];
+        let token_len = match rest.chars().next() {
+            None => 0,
+            Some(c) if c.is_alphanumeric() || c == '_' => rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len()),
+            // Report LaTeX-Commands as a whole
+            Some('\\') => rest[1..]
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .map_or(rest.len(), |i| i + 1),
+            Some(c) => c.len_utf8(),
+        };
+
+        if (pos >= source.len()) {
+            ParseError::UnexpectedEndOfInput {
+                span: pos..pos,
+                line,
+                column,
+                expected,
+                source_line,
+            }
+        } else {
+            ParseError::UnexpectedToken {
+                span: pos..(pos + token_len),
+                line,
+                column,
+                expected,
+                found: rest[..token_len].to_string(),
+                source_line,
+            }
+        }
+    }
+    /// Byte-Range of the offending Input.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            ParseError::UnexpectedToken { span, .. }
+            | ParseError::UnexpectedEndOfInput { span, .. } => span.clone(),
+        }
+    }
+    pub fn line(&self) -> usize {
+        match self {
+            ParseError::UnexpectedToken { line, .. }
+            | ParseError::UnexpectedEndOfInput { line, .. } => *line,
+        }
+    }
+    pub fn column(&self) -> usize {
+        match self {
+            ParseError::UnexpectedToken { column, .. }
+            | ParseError::UnexpectedEndOfInput { column, .. } => *column,
+        }
+    }
+    /// Human-readable Names of the Tokens that would have been accepted.
+    pub fn expected(&self) -> &[String] {
+        match self {
+            ParseError::UnexpectedToken { expected, .. }
+            | ParseError::UnexpectedEndOfInput { expected, .. } => expected,
+        }
+    }
+    /// The offending Input, empty if the Input ended unexpectedly.
+    pub fn found(&self) -> &str {
+        match self {
+            ParseError::UnexpectedToken { found, .. } => found,
+            ParseError::UnexpectedEndOfInput { .. } => "",
+        }
+    }
+    /// Renders the Error together with the offending Source-Line and a Caret-Underline.
+    ///
+    /// ```text
+    /// error: unexpected `B`, expected one of `&`, `|`, end of input
+    ///  --> 1:3
+    ///   |
+    /// 1 | A B
+    ///   |   ^
+    /// ```
+    pub fn render(&self) -> String {
+        let (source_line, line, column) = match self {
+            ParseError::UnexpectedToken {
+                source_line,
+                line,
+                column,
+                ..
+            }
+            | ParseError::UnexpectedEndOfInput {
+                source_line,
+                line,
+                column,
+                ..
+            } => (source_line, *line, *column),
+        };
+        let gutter = " ".repeat(line.to_string().len());
+        let carets = "^".repeat(self.found().chars().count().max(1));
+
+        format!(
+            "error: {}\n{}--> {}:{}\n{} |\n{} | {}\n{} | {}{}\n",
+            self,
+            gutter,
+            line,
+            column,
+            gutter,
+            line,
+            source_line,
+            gutter,
+            " ".repeat(column - 1),
+            carets
+        )
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // Only Line-based Formats like DIMACS expect more on the same Line
+            ParseError::UnexpectedToken { found, .. } if found.is_empty() => {
+                write!(f, "unexpected end of line")?
+            }
+            ParseError::UnexpectedToken { found, .. } => write!(f, "unexpected `{}`", found)?,
+            ParseError::UnexpectedEndOfInput { .. } => write!(f, "unexpected end of input")?,
+        };
+        match self.expected() {
+            [] => Ok(()),
+            [single] => write!(f, ", expected {}", single),
+            expected => write!(f, ", expected one of {}", expected.join(", ")),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Error returned when an Expression can't be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvaluationError {
+    /// No Value was given for the named Variable.
+    UnboundVariable(String),
+    /// The Index doesn't belong to a Row of the Truth-Table.
+    RowOutOfRange(usize),
+}
+
+impl Display for EvaluationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvaluationError::UnboundVariable(name) => write!(f, "unbound variable `{}`", name),
+            EvaluationError::RowOutOfRange(row) => write!(f, "row {} is out of range", row),
+        }
+    }
+}
+
+impl Error for EvaluationError {}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Error returned when a Normal Form would get too large, see `NormalFormOptions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormalFormError {
+    /// The Normal Form needs more Terms than the given Limit.
+    TooManyTerms(usize),
+}
+
+impl Display for NormalFormError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalFormError::TooManyTerms(limit) => {
+                write!(f, "normal form needs more than {} terms", limit)
+            }
+        }
+    }
+}
+
+impl Error for NormalFormError {}
+
+/* --------------------------------------------------------------------------------------------- */
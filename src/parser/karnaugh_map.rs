@@ -0,0 +1,342 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::{ast_node::ASTNode, printer::Printer};
+use crate::solver::{
+    minterm::{Minterm, TruthValue},
+    petrick::CoverCost,
+    quine_mccluskey,
+};
+
+use std::fmt::{Display, Formatter};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Karnaugh-Map of an Expression with up to six Variables, see `Parsed::karnaugh_map`.
+///
+/// The first half of the Variables (rounded down) selects the Row, the others the Column.
+/// Both Axes are labeled in reflected Gray-Code, so neighbouring Cells differ in exactly one
+/// Variable. With three Variables on an Axis this is the mirrored Layout of two 4-wide Maps,
+/// where Cells at the same Distance from the Mirror-Line are neighbours as well.
+#[derive(Debug, Clone)]
+pub struct KarnaughMap {
+    variables: Vec<String>,
+    row_bits: usize,
+    values: Vec<TruthValue>,
+    groups: Vec<Minterm>,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl KarnaughMap {
+    /// Largest Number of Variables a Map can be drawn for.
+    pub const MAX_VARIABLES: usize = 6;
+
+    /// Map of the Values indexed by the Rows of the Truth-Table.
+    pub(crate) fn new(variables: Vec<String>, values: Vec<TruthValue>) -> Self {
+        debug_assert!(variables.len() <= Self::MAX_VARIABLES);
+        Self {
+            row_bits: variables.len() / 2,
+            variables,
+            values,
+            groups: Vec::new(),
+        }
+    }
+    /// Outlines the given Product-Terms, e.g. the Result of a Minimizer.
+    pub fn with_groups(mut self, groups: Vec<Minterm>) -> Self {
+        self.groups = groups;
+        self
+    }
+    /// Outlines the Prime-Implicants of a minimal Sum-of-Products Form, using the Don't-Cares.
+    pub fn with_minimal_groups(self) -> Self {
+        let rows = |value: TruthValue| -> Vec<usize> {
+            (0..self.values.len())
+                .filter(|idx| self.values[*idx] == value)
+                .collect()
+        };
+        let groups = quine_mccluskey::minimize_with(
+            &rows(TruthValue::True),
+            &rows(TruthValue::DontCare),
+            self.variables.len(),
+            CoverCost::default(),
+        );
+        self.with_groups(groups)
+    }
+    pub fn groups(&self) -> &[Minterm] {
+        &self.groups
+    }
+    pub fn num_rows(&self) -> usize {
+        1 << self.row_bits
+    }
+    pub fn num_columns(&self) -> usize {
+        1 << self.column_bits()
+    }
+    /// Index of the Truth-Table-Row shown in the Cell.
+    pub fn index(&self, row: usize, column: usize) -> usize {
+        (gray(row) << self.column_bits()) | gray(column)
+    }
+    /// Value of the Cell, `DontCare` for the Don't-Cares of the Input.
+    pub fn cell(&self, row: usize, column: usize) -> TruthValue {
+        self.values[self.index(row, column)]
+    }
+    pub fn render_ascii(&self) -> String {
+        let (row_label, column_label) = self.axis_labels();
+        let corner = format!("{} \\ {}", row_label, column_label);
+        let cells: Vec<Vec<String>> = (0..self.num_rows())
+            .map(|row| {
+                (0..self.num_columns())
+                    .map(|column| self.ascii_cell(row, column))
+                    .collect()
+            })
+            .collect();
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|cell| cell.chars().count())
+            .chain(std::iter::once(self.column_bits()))
+            .max()
+            .unwrap_or(1);
+        let corner_width = corner.chars().count().max(self.row_bits);
+
+        let header: Vec<String> = (0..self.num_columns())
+            .map(|column| format!("{:<w$}", code(column, self.column_bits()), w = width))
+            .collect();
+        let mut out = format!("{:>w$} | {}\n", corner, header.join(" "), w = corner_width);
+        out.push_str(&"-".repeat(corner_width + 1));
+        out.push('+');
+        out.push_str(&"-".repeat(self.num_columns() * (width + 1)));
+        out.push('\n');
+        for (row, cells) in cells.iter().enumerate() {
+            let cells: Vec<String> = cells
+                .iter()
+                .map(|cell| format!("{:<w$}", cell, w = width))
+                .collect();
+            out.push_str(&format!(
+                "{:>w$} | {}\n",
+                code(row, self.row_bits),
+                cells.join(" ").trim_end(),
+                w = corner_width
+            ));
+        }
+        for (idx, group) in self.groups.iter().enumerate() {
+            out.push_str(&format!("{}: {}\n", group_name(idx), self.term(group)));
+        }
+        out
+    }
+    /// Renders the Map as a standalone SVG-Image, Groups are drawn as rounded Rectangles and
+    /// split where they wrap around an Edge of the Map.
+    pub fn render_svg(&self) -> String {
+        const CELL: usize = 40;
+        const MARGIN: usize = 60;
+        const COLORS: [&str; 6] = [
+            "#d62728", "#1f77b4", "#2ca02c", "#ff7f0e", "#9467bd", "#8c564b",
+        ];
+        let (row_label, column_label) = self.axis_labels();
+        let width = MARGIN + self.num_columns() * CELL + 10;
+        let height = MARGIN + self.num_rows() * CELL + 10;
+
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             font-family=\"monospace\" font-size=\"14\" text-anchor=\"middle\">\n",
+            width, height
+        );
+        out.push_str(&format!(
+            "  <line x1=\"0\" y1=\"0\" x2=\"{m}\" y2=\"{m}\" stroke=\"black\"/>\n",
+            m = MARGIN
+        ));
+        out.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\">{}</text>\n",
+            MARGIN * 3 / 4,
+            MARGIN / 3,
+            escape(&column_label)
+        ));
+        out.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\">{}</text>\n",
+            MARGIN / 4,
+            MARGIN * 3 / 4,
+            escape(&row_label)
+        ));
+        for column in 0..self.num_columns() {
+            out.push_str(&format!(
+                "  <text x=\"{}\" y=\"{}\">{}</text>\n",
+                MARGIN + column * CELL + CELL / 2,
+                MARGIN - 8,
+                code(column, self.column_bits())
+            ));
+        }
+        for row in 0..self.num_rows() {
+            out.push_str(&format!(
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>\n",
+                MARGIN - 6,
+                MARGIN + row * CELL + CELL / 2 + 5,
+                code(row, self.row_bits)
+            ));
+            for column in 0..self.num_columns() {
+                let (x, y) = (MARGIN + column * CELL, MARGIN + row * CELL);
+                out.push_str(&format!(
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{c}\" height=\"{c}\" fill=\"none\" \
+                     stroke=\"black\"/>\n",
+                    x,
+                    y,
+                    c = CELL
+                ));
+                out.push_str(&format!(
+                    "  <text x=\"{}\" y=\"{}\">{}</text>\n",
+                    x + CELL / 2,
+                    y + CELL / 2 + 5,
+                    value_char(self.cell(row, column))
+                ));
+            }
+        }
+        for (idx, group) in self.groups.iter().enumerate() {
+            // Nested Groups are inset a little further, so their Outlines don't overlap
+            let inset = 3 + 3 * (idx % 4);
+            let color = COLORS[idx % COLORS.len()];
+            out.push_str(&format!(
+                "  <g fill=\"none\" stroke=\"{}\" stroke-width=\"2\">\n",
+                color
+            ));
+            out.push_str(&format!(
+                "    <title>{}</title>\n",
+                escape(&self.term(group))
+            ));
+            for (rows, columns) in self.group_rectangles(group) {
+                out.push_str(&format!(
+                    "    <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"10\"/>\n",
+                    MARGIN + columns.start * CELL + inset,
+                    MARGIN + rows.start * CELL + inset,
+                    columns.len() * CELL - 2 * inset,
+                    rows.len() * CELL - 2 * inset
+                ));
+            }
+            out.push_str("  </g>\n");
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl KarnaughMap {
+    fn column_bits(&self) -> usize {
+        self.variables.len() - self.row_bits
+    }
+    /// Names of the Row- and of the Column-Variables.
+    fn axis_labels(&self) -> (String, String) {
+        let printer = Printer::new();
+        let names: Vec<String> = (0..self.variables.len())
+            .map(|i| printer.print(&ASTNode::Identifier(i), &self.variables))
+            .collect();
+        let (rows, columns) = names.split_at(self.row_bits);
+        (rows.join(""), columns.join(""))
+    }
+    fn ascii_cell(&self, row: usize, column: usize) -> String {
+        let index = self.index(row, column);
+        let mut cell = value_char(self.values[index]).to_string();
+        for (idx, group) in self.groups.iter().enumerate() {
+            if group.covers(index) {
+                cell.push_str(&group_name(idx));
+            }
+        }
+        cell
+    }
+    fn term(&self, group: &Minterm) -> String {
+        Printer::new().print(&group.to_ast(), &self.variables)
+    }
+    /// Rectangles of Rows and Columns covered by the Group, one for every combination of a
+    /// contiguous Run of Rows and a contiguous Run of Columns.
+    fn group_rectangles(
+        &self,
+        group: &Minterm,
+    ) -> Vec<(std::ops::Range<usize>, std::ops::Range<usize>)> {
+        let values = group.values();
+        let (row_values, column_values) = values.split_at(self.row_bits);
+        let rows = runs(self.num_rows(), |row| matches(row_values, gray(row)));
+        let columns = runs(self.num_columns(), |column| {
+            matches(column_values, gray(column))
+        });
+        rows.iter()
+            .flat_map(|r| columns.iter().map(move |c| (r.clone(), c.clone())))
+            .collect()
+    }
+}
+
+/// Prints the Map as aligned plain Text, Cells are followed by the Names of their Groups.
+impl Display for KarnaughMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render_ascii())
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Reflected Gray-Code of the Position on an Axis.
+fn gray(position: usize) -> usize {
+    position ^ (position >> 1)
+}
+
+/// Gray-Code of the Position as Bit-String, e.g. "11" for the third of four.
+fn code(position: usize, bits: usize) -> String {
+    (0..bits)
+        .rev()
+        .map(|bit| {
+            if (gray(position) >> bit) & 1 == 1 {
+                '1'
+            } else {
+                '0'
+            }
+        })
+        .collect()
+}
+
+/// Checks if the Bits of `code` agree with the Literals of a Term, the first Literal belongs
+/// to the most significant Bit.
+fn matches(literals: &[TruthValue], code: usize) -> bool {
+    let n = literals.len();
+    literals.iter().enumerate().all(|(i, t)| match t {
+        TruthValue::DontCare => true,
+        TruthValue::True => (code >> (n - 1 - i)) & 1 == 1,
+        TruthValue::False => (code >> (n - 1 - i)) & 1 == 0,
+    })
+}
+
+/// Contiguous Runs of Positions for which the Predicate holds.
+fn runs<F: Fn(usize) -> bool>(len: usize, predicate: F) -> Vec<std::ops::Range<usize>> {
+    let mut runs: Vec<std::ops::Range<usize>> = Vec::new();
+    for position in (0..len).filter(|p| predicate(*p)) {
+        match runs.last_mut() {
+            Some(run) if run.end == position => run.end += 1,
+            _ => runs.push(position..position + 1),
+        }
+    }
+    runs
+}
+
+fn value_char(value: TruthValue) -> char {
+    match value {
+        TruthValue::True => '1',
+        TruthValue::False => '0',
+        TruthValue::DontCare => 'X',
+    }
+}
+
+/// Lower-case Letters, continued with "aa", "ab", ... for more than 26 Groups.
+fn group_name(idx: usize) -> String {
+    let letter = |i: usize| ((b'a' + i as u8) as char).to_string();
+    if (idx < 26) {
+        letter(idx)
+    } else {
+        format!("{}{}", letter(idx / 26 - 1), letter(idx % 26))
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -1,4 +1,13 @@
 pub(crate) mod ast_node;
-// pub(crate) mod binding;
+pub(crate) mod binding;
+pub(crate) mod dialect;
+pub(crate) mod dimacs;
+pub(crate) mod error;
+pub(crate) mod karnaugh_map;
+pub(crate) mod normal_form;
 pub(crate) mod operators;
 pub mod parsing;
+pub(crate) mod printer;
+pub(crate) mod simplifier;
+pub(crate) mod truth_table;
+pub(crate) mod tseitin;
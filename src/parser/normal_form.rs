@@ -0,0 +1,489 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::{
+    ast_node::ASTNode,
+    error::NormalFormError,
+    operators::{InfixOperator, UnaryOperator},
+    simplifier::{chain, operands},
+};
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// A Variable, given by its Integer-Identifier, or its Negation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct Literal {
+    pub variable: usize,
+    pub negated: bool,
+}
+
+/// Disjunction of Literals, false without any.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct Clause {
+    pub literals: Vec<Literal>,
+}
+
+/// Conjunction of Literals, true without any.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct Cube {
+    pub literals: Vec<Literal>,
+}
+
+/// Conjunctive Normal Form: a Conjunction of Clauses, true without any.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Cnf {
+    pub clauses: Vec<Clause>,
+}
+
+/// Disjunctive Normal Form: a Disjunction of Cubes, false without any.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Dnf {
+    pub cubes: Vec<Cube>,
+}
+
+/// Options for `ASTNode::to_cnf` and `ASTNode::to_dnf`.
+///
+/// Distributing And over Or (or the other way around) can multiply the Number of Terms, e.g.
+/// the CNF of `(a & b) | (c & d) | ...` has 2^n Clauses. The Conversion fails with
+/// `NormalFormError::TooManyTerms` as soon as it needs more Terms than the Limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalFormOptions {
+    max_terms: usize,
+    canonical: bool,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Literal {
+    pub fn new(variable: usize, negated: bool) -> Self {
+        Self { variable, negated }
+    }
+    pub fn to_ast(&self) -> ASTNode {
+        let variable = ASTNode::Identifier(self.variable);
+        if (self.negated) {
+            variable.negate()
+        } else {
+            variable
+        }
+    }
+    /// Value of the Literal for the Values indexed by Integer-Identifier.
+    pub fn evaluate(&self, values: &[bool]) -> bool {
+        values[self.variable] != self.negated
+    }
+}
+
+impl std::ops::Not for Literal {
+    type Output = Literal;
+
+    fn not(self) -> Self::Output {
+        Literal::new(self.variable, !self.negated)
+    }
+}
+
+/// Prints the Literal in DIMACS-Style, the Variables counted from 1, e.g. "-3".
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if (self.negated) {
+            write!(f, "-");
+        }
+        write!(f, "{}", self.variable + 1)
+    }
+}
+
+impl Clause {
+    pub fn to_ast(&self) -> ASTNode {
+        chain(InfixOperator::Or, self.literals.iter().map(Literal::to_ast))
+    }
+    pub fn evaluate(&self, values: &[bool]) -> bool {
+        self.literals.iter().any(|l| l.evaluate(values))
+    }
+}
+
+impl Cube {
+    pub fn to_ast(&self) -> ASTNode {
+        chain(
+            InfixOperator::And,
+            self.literals.iter().map(Literal::to_ast),
+        )
+    }
+    pub fn evaluate(&self, values: &[bool]) -> bool {
+        self.literals.iter().all(|l| l.evaluate(values))
+    }
+}
+
+impl Cnf {
+    pub fn to_ast(&self) -> ASTNode {
+        chain(InfixOperator::And, self.clauses.iter().map(Clause::to_ast))
+    }
+    pub fn evaluate(&self, values: &[bool]) -> bool {
+        self.clauses.iter().all(|c| c.evaluate(values))
+    }
+}
+
+impl Dnf {
+    pub fn to_ast(&self) -> ASTNode {
+        chain(InfixOperator::Or, self.cubes.iter().map(Cube::to_ast))
+    }
+    pub fn evaluate(&self, values: &[bool]) -> bool {
+        self.cubes.iter().any(|c| c.evaluate(values))
+    }
+}
+
+impl NormalFormOptions {
+    /// Default Limit on the Number of Terms.
+    pub const MAX_TERMS: usize = 10_000;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_max_terms(mut self, max_terms: usize) -> Self {
+        self.max_terms = max_terms;
+        self
+    }
+    /// Canonical Forms mention every Variable in every Term, i.e. they list the Maxterms of
+    /// the false Rows or the Minterms of the true Rows, sorted by the Index of the Row.
+    pub fn with_canonical(mut self, canonical: bool) -> Self {
+        self.canonical = canonical;
+        self
+    }
+}
+
+impl Default for NormalFormOptions {
+    fn default() -> Self {
+        Self {
+            max_terms: Self::MAX_TERMS,
+            canonical: false,
+        }
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Clauses of the Conjunctive Normal Form of the Expression over the first `num_variables`
+/// Variables, see `terms`.
+pub(crate) fn cnf(
+    node: &ASTNode,
+    num_variables: usize,
+    options: NormalFormOptions,
+) -> Result<Cnf, NormalFormError> {
+    let clauses = terms(node, InfixOperator::And, num_variables, options)?;
+    Ok(Cnf {
+        clauses: clauses
+            .into_iter()
+            .map(|literals| Clause { literals })
+            .collect(),
+    })
+}
+
+/// Cubes of the Disjunctive Normal Form of the Expression over the first `num_variables`
+/// Variables, see `terms`.
+pub(crate) fn dnf(
+    node: &ASTNode,
+    num_variables: usize,
+    options: NormalFormOptions,
+) -> Result<Dnf, NormalFormError> {
+    let cubes = terms(node, InfixOperator::Or, num_variables, options)?;
+    Ok(Dnf {
+        cubes: cubes
+            .into_iter()
+            .map(|literals| Cube { literals })
+            .collect(),
+    })
+}
+
+/// Terms of the Normal Form whose outer Operator is `outer`: Clauses for And, Cubes for Or.
+///
+/// The Expression is converted bottom-up, pushing Negations down like `nnf` does. Operands
+/// joined by the outer Operator just concatenate their Terms, Operands joined by the inner one
+/// are distributed, i.e. every Term of the one is combined with every Term of the other. Xor
+/// and Equivalence combine the Terms of both Operands and of their Negations, which are
+/// converted once each, instead of doubling the Subtrees like the Negation-Normal-Form, so
+/// the Limit applies before anything grows. Terms with a Variable in both Polarities are
+/// dropped (such a Clause is true, such a Cube false), as are Terms containing every Literal
+/// of another Term.
+fn terms(
+    node: &ASTNode,
+    outer: InfixOperator,
+    num_variables: usize,
+    options: NormalFormOptions,
+) -> Result<Vec<Vec<Literal>>, NormalFormError> {
+    let terms = Distribution::new(outer, options.max_terms).terms(node, false)?;
+    if (!options.canonical) {
+        return Ok(terms);
+    }
+    let mut rows: Vec<Vec<Literal>> = Vec::new();
+    for term in terms {
+        let mut expanded: Vec<Vec<Literal>> = vec![Vec::new()];
+        for variable in 0..num_variables {
+            match term.iter().find(|l| l.variable == variable) {
+                Some(literal) => expanded.iter_mut().for_each(|e| e.push(*literal)),
+                None => {
+                    expanded = expanded
+                        .into_iter()
+                        .flat_map(|e| {
+                            [false, true].map(|negated| {
+                                let mut e = e.clone();
+                                e.push(Literal::new(variable, negated));
+                                e
+                            })
+                        })
+                        .collect();
+                }
+            }
+            check_limit(rows.len() + expanded.len(), options.max_terms)?;
+        }
+        rows.extend(expanded);
+    }
+    // The Row a Minterm is true in, or a Maxterm is false in, with the first Variable as MSB
+    let row = |term: &Vec<Literal>| -> usize {
+        term.iter().fold(0, |row, l| {
+            (row << 1) | ((l.negated == (outer == InfixOperator::And)) as usize)
+        })
+    };
+    rows.sort_by_key(row);
+    rows.dedup();
+    Ok(rows)
+}
+
+/// Converts Expressions into the Terms of a Normal Form, see `terms`.
+struct Distribution {
+    outer: InfixOperator,
+    max_terms: usize,
+    /// Terms of the Subtrees by their Address and Polarity. Xor and Equivalence need both
+    /// Polarities of their Operands, without this Chains of them would be converted again and
+    /// again.
+    known: HashMap<(*const ASTNode, bool), Vec<Vec<Literal>>>,
+}
+
+impl Distribution {
+    fn new(outer: InfixOperator, max_terms: usize) -> Self {
+        Self {
+            outer,
+            max_terms,
+            known: HashMap::new(),
+        }
+    }
+    /// Terms of the Expression, or of its Negation if `negated` is set.
+    fn terms(
+        &mut self,
+        node: &ASTNode,
+        negated: bool,
+    ) -> Result<Vec<Vec<Literal>>, NormalFormError> {
+        if let Some(terms) = self.known.get(&(node as *const ASTNode, negated)) {
+            return Ok(terms.clone());
+        }
+        let terms = match node {
+            // A single empty Term is the neutral Element of the inner Operator
+            ASTNode::Literal(l) if (*l != negated) == (self.outer == InfixOperator::Or) => {
+                vec![Vec::new()]
+            }
+            ASTNode::Literal(_) => Vec::new(),
+            ASTNode::Identifier(i) => vec![vec![Literal::new(*i, negated)]],
+            ASTNode::UnaryOperation {
+                op: UnaryOperator::Not,
+                expr,
+            } => self.terms(expr, !negated)?,
+            ASTNode::InfixOperation { op, lhs, rhs } => match op {
+                InfixOperator::And | InfixOperator::Or => {
+                    // !(x & y) = !x | !y, !(x | y) = !x & !y
+                    let op = match (op, negated) {
+                        (InfixOperator::And, false) | (InfixOperator::Or, true) => {
+                            InfixOperator::And
+                        }
+                        _ => InfixOperator::Or,
+                    };
+                    self.operation(op, (lhs, negated), (rhs, negated))?
+                }
+                // !(x -> y) = x & !y
+                InfixOperator::Implication if negated => {
+                    self.operation(InfixOperator::And, (lhs, false), (rhs, true))?
+                }
+                // x -> y = !x | y
+                InfixOperator::Implication => {
+                    self.operation(InfixOperator::Or, (lhs, true), (rhs, false))?
+                }
+                InfixOperator::Xor | InfixOperator::Equivalence => {
+                    // Written with the outer Operator on top, so that its Operands only need
+                    // to be concatenated: x <-> y = (x & y) | (!x & !y) = (x | !y) & (!x | y)
+                    // and x ^ y = (x & !y) | (!x & y) = (x | y) & (!x | !y)
+                    let equal = (*op == InfixOperator::Equivalence) != negated;
+                    let (inner, rhs_negated) = match self.outer {
+                        InfixOperator::Or => (InfixOperator::And, !equal),
+                        _ => (InfixOperator::Or, equal),
+                    };
+                    let first = self.operation(inner, (lhs, false), (rhs, rhs_negated))?;
+                    let second = self.operation(inner, (lhs, true), (rhs, !rhs_negated))?;
+                    combine(self.outer, first, second, self.outer, self.max_terms)?
+                }
+            },
+            _ => unreachable!(),
+        };
+        let terms = remove_absorbed(terms);
+        check_limit(terms.len(), self.max_terms)?;
+        self.known
+            .insert((node as *const ASTNode, negated), terms.clone());
+        Ok(terms)
+    }
+    fn operation(
+        &mut self,
+        op: InfixOperator,
+        (lhs, lhs_negated): (&ASTNode, bool),
+        (rhs, rhs_negated): (&ASTNode, bool),
+    ) -> Result<Vec<Vec<Literal>>, NormalFormError> {
+        let lhs = self.terms(lhs, lhs_negated)?;
+        let rhs = self.terms(rhs, rhs_negated)?;
+        combine(op, lhs, rhs, self.outer, self.max_terms)
+    }
+}
+
+/// Terms of the Operation on two Operands given by their Terms: concatenated for the outer
+/// Operator, every Pair combined for the inner one.
+fn combine(
+    op: InfixOperator,
+    lhs: Vec<Vec<Literal>>,
+    rhs: Vec<Vec<Literal>>,
+    outer: InfixOperator,
+    max_terms: usize,
+) -> Result<Vec<Vec<Literal>>, NormalFormError> {
+    if (op == outer) {
+        let terms = remove_absorbed([lhs, rhs].concat());
+        check_limit(terms.len(), max_terms)?;
+        return Ok(terms);
+    }
+    let mut product = Vec::new();
+    for l in lhs.iter() {
+        for r in rhs.iter() {
+            let mut term = [l.as_slice(), r.as_slice()].concat();
+            term.sort();
+            term.dedup();
+            if !term.windows(2).any(|w| w[0].variable == w[1].variable) {
+                product.push(term);
+                check_limit(product.len(), max_terms)?;
+            }
+        }
+    }
+    Ok(remove_absorbed(product))
+}
+
+/// Removes duplicate Terms and Terms containing every Literal of another Term.
+fn remove_absorbed(mut terms: Vec<Vec<Literal>>) -> Vec<Vec<Literal>> {
+    terms.sort_by_key(Vec::len);
+    let mut seen: HashSet<Vec<Literal>> = HashSet::new();
+    let mut result: Vec<Vec<Literal>> = Vec::with_capacity(terms.len());
+    for term in terms {
+        // Only shorter Terms can absorb it, Terms of the same Length only if they are equal
+        let absorbed = seen.contains(&term)
+            || result
+                .iter()
+                .take_while(|r| r.len() < term.len())
+                .any(|r| r.iter().all(|l| term.contains(l)));
+        if (!absorbed) {
+            seen.insert(term.clone());
+            result.push(term);
+        }
+    }
+    result
+}
+
+fn check_limit(num_terms: usize, max_terms: usize) -> Result<(), NormalFormError> {
+    if (num_terms > max_terms) {
+        return Err(NormalFormError::TooManyTerms(max_terms));
+    }
+    Ok(())
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Negation-Normal-Form of the Expression, or of its Negation if `negated` is set.
+///
+/// Negations are pushed down with De Morgan's Laws until they only apply to Variables, and
+/// Implication, Xor and Equivalence are rewritten with And, Or and Not. Negated Constants are
+/// folded.
+pub(crate) fn nnf(node: &ASTNode, negated: bool) -> ASTNode {
+    match node {
+        ASTNode::Literal(l) => ASTNode::Literal(*l != negated),
+        ASTNode::Identifier(_) if negated => node.clone().negate(),
+        ASTNode::Identifier(_) => node.clone(),
+        ASTNode::UnaryOperation {
+            op: UnaryOperator::Not,
+            expr,
+        } => nnf(expr, !negated),
+        ASTNode::InfixOperation { op, lhs, rhs } => match op {
+            InfixOperator::And | InfixOperator::Or => {
+                // !(x & y) = !x | !y, !(x | y) = !x & !y
+                let op = match (op, negated) {
+                    (InfixOperator::And, false) | (InfixOperator::Or, true) => InfixOperator::And,
+                    _ => InfixOperator::Or,
+                };
+                ASTNode::infix(op, nnf(lhs, negated), nnf(rhs, negated))
+            }
+            InfixOperator::Implication if negated => {
+                // !(x -> y) = x & !y
+                ASTNode::infix(InfixOperator::And, nnf(lhs, false), nnf(rhs, true))
+            }
+            // x -> y = !x | y
+            InfixOperator::Implication => {
+                ASTNode::infix(InfixOperator::Or, nnf(lhs, true), nnf(rhs, false))
+            }
+            InfixOperator::Xor | InfixOperator::Equivalence => {
+                // x <-> y = (x & y) | (!x & !y), x ^ y = (x & !y) | (!x & y)
+                let equal = (*op == InfixOperator::Equivalence) != negated;
+                ASTNode::infix(
+                    InfixOperator::Or,
+                    ASTNode::infix(InfixOperator::And, nnf(lhs, false), nnf(rhs, !equal)),
+                    ASTNode::infix(InfixOperator::And, nnf(lhs, true), nnf(rhs, equal)),
+                )
+            }
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Clauses of the Expression if it already is a Conjunction of Disjunctions of Variables and
+/// negated Variables, or one of the Constants.
+pub(crate) fn as_cnf(node: &ASTNode) -> Option<Cnf> {
+    let clauses = match node {
+        ASTNode::Literal(true) => Vec::new(),
+        ASTNode::Literal(false) => vec![Clause {
+            literals: Vec::new(),
+        }],
+        _ => operands(InfixOperator::And, node)
+            .into_iter()
+            .map(|clause| {
+                let literals = operands(InfixOperator::Or, clause)
+                    .into_iter()
+                    .map(|literal| match literal {
+                        ASTNode::Identifier(i) => Some(Literal::new(*i, false)),
+                        ASTNode::UnaryOperation { expr, .. } => match **expr {
+                            ASTNode::Identifier(i) => Some(Literal::new(i, true)),
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<Literal>>>()?;
+                Some(Clause { literals })
+            })
+            .collect::<Option<Vec<Clause>>>()?,
+    };
+    Some(Cnf { clauses })
+}
+
+/// Checks if Negations only apply to Variables and the only Operators are And and Or.
+pub(crate) fn is_nnf(node: &ASTNode) -> bool {
+    match node {
+        ASTNode::Literal(_) | ASTNode::Identifier(_) => true,
+        ASTNode::UnaryOperation { expr, .. } => matches!(**expr, ASTNode::Identifier(_)),
+        ASTNode::InfixOperation {
+            op: InfixOperator::And | InfixOperator::Or,
+            lhs,
+            rhs,
+        } => is_nnf(lhs) && is_nnf(rhs),
+        _ => false,
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -3,6 +3,7 @@
 /* --------------------------------------------------------------------------------------------- */
 
 use crate::parser::ast_node::ASTNode;
+use crate::parser::dialect::Notation;
 use std::fmt::{Debug, Display, Formatter};
 
 /* --------------------------------------------------------------------------------------------- */
@@ -14,7 +15,10 @@ pub enum Assoc {
     NotApplicable,
 }
 
-trait Operator {
+/// Binding-Behaviour of an Operator. The Parser folds Operator-Chains according to `assoc`,
+/// but the Grammar spells out one Rule per `precedence`-Level (higher binds tighter) by Hand,
+/// the Tests check that the Rules are ordered like the Levels.
+pub trait Operator {
     fn assoc(&self) -> Assoc {
         Assoc::NotApplicable
     }
@@ -37,6 +41,16 @@ impl UnaryOperator {
             _ => unreachable!(),
         }
     }
+    /// Spelling of the Operator in the given Notation.
+    pub fn symbol(&self, notation: Notation) -> &'static str {
+        match (self, notation) {
+            (UnaryOperator::Not, Notation::Ascii | Notation::CStyle) => "!",
+            (UnaryOperator::Not, Notation::Unicode) => "¬",
+            (UnaryOperator::Not, Notation::Word) => "NOT",
+            (UnaryOperator::Not, Notation::Latex) => "\\neg",
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl Operator for UnaryOperator {
@@ -44,7 +58,10 @@ impl Operator for UnaryOperator {
         Assoc::NotApplicable
     }
     fn precedence(&self) -> usize {
-        0
+        match self {
+            UnaryOperator::Not => 5,
+            _ => unreachable!(),
+        }
     }
 }
 
@@ -60,7 +77,7 @@ impl Debug for UnaryOperator {
 impl Display for UnaryOperator {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            UnaryOperator::Not => write!(f, "!"),
+            UnaryOperator::Not => write!(f, "{}", self.symbol(Notation::Ascii)),
             _ => unreachable!(),
         }
     }
@@ -72,7 +89,9 @@ impl Display for UnaryOperator {
 pub enum InfixOperator {
     And,
     Or,
+    Xor,
     Implication,
+    Equivalence,
 }
 
 impl InfixOperator {
@@ -80,55 +99,56 @@ impl InfixOperator {
         match self {
             InfixOperator::And => lhs & rhs,
             InfixOperator::Or => lhs | rhs,
+            InfixOperator::Xor => lhs ^ rhs,
             InfixOperator::Implication => (!lhs) | rhs,
+            InfixOperator::Equivalence => lhs == rhs,
             _ => unreachable!(),
         }
     }
-    pub fn simplify(self, lhs: ASTNode, rhs: ASTNode) -> ASTNode {
-        let lhs = lhs.try_simplify();
-        let rhs = rhs.try_simplify();
-
-        match (lhs, rhs) {
-            (ASTNode::Literal(l1), ASTNode::Literal(l2)) => ASTNode::Literal(self.apply(l1, l2)),
-            (a1, ASTNode::Literal(l2)) => match (self, l2) {
-                (InfixOperator::And, true)
-                | (InfixOperator::Or, false)
-                | (InfixOperator::Implication, false) => a1,
-                (InfixOperator::And, false) => ASTNode::Literal(false),
-                (InfixOperator::Or, true) | (InfixOperator::Implication, true) => {
-                    ASTNode::Literal(true)
-                }
+    /// Spelling of the Operator in the given Notation.
+    /// C has no Implication-Operator, the Printer writes `!a || b` instead.
+    pub fn symbol(&self, notation: Notation) -> &'static str {
+        match notation {
+            Notation::Ascii => match self {
+                InfixOperator::And => "&",
+                InfixOperator::Or => "|",
+                InfixOperator::Xor => "^",
+                InfixOperator::Implication => "->",
+                InfixOperator::Equivalence => "<->",
             },
-            (ASTNode::Literal(l1), a2) => match (self, l1) {
-                (InfixOperator::And, true)
-                | (InfixOperator::Or, false)
-                | (InfixOperator::Implication, true) => a2,
-                (InfixOperator::And, false) => ASTNode::Literal(false),
-                (InfixOperator::Or, true) | (InfixOperator::Implication, false) => {
-                    ASTNode::Literal(true)
-                }
+            Notation::Unicode => match self {
+                InfixOperator::And => "∧",
+                InfixOperator::Or => "∨",
+                InfixOperator::Xor => "⊕",
+                InfixOperator::Implication => "→",
+                InfixOperator::Equivalence => "↔",
             },
-            (ASTNode::Identifier(i1), ASTNode::Identifier(i2)) => {
-                if (i1 == i2) {
-                    match self {
-                        InfixOperator::And => ASTNode::Identifier(i1),
-                        InfixOperator::Or | InfixOperator::Implication => ASTNode::Literal(true),
-                    }
-                } else {
-                    ASTNode::InfixOperation {
-                        op: self,
-                        lhs: Box::new(ASTNode::Identifier(i1)),
-                        rhs: Box::new(ASTNode::Identifier(i2)),
-                    }
-                }
-            }
-            (lhs, rhs) => ASTNode::InfixOperation {
-                op: self,
-                lhs: Box::new(lhs),
-                rhs: Box::new(rhs),
+            Notation::Word => match self {
+                InfixOperator::And => "AND",
+                InfixOperator::Or => "OR",
+                InfixOperator::Xor => "XOR",
+                InfixOperator::Implication => "IMPLIES",
+                InfixOperator::Equivalence => "IFF",
+            },
+            Notation::CStyle => match self {
+                InfixOperator::And => "&&",
+                InfixOperator::Or | InfixOperator::Implication => "||",
+                InfixOperator::Xor => "!=",
+                InfixOperator::Equivalence => "==",
+            },
+            Notation::Latex => match self {
+                InfixOperator::And => "\\land",
+                InfixOperator::Or => "\\lor",
+                InfixOperator::Xor => "\\oplus",
+                InfixOperator::Implication => "\\to",
+                InfixOperator::Equivalence => "\\leftrightarrow",
             },
         }
     }
+    /// Simplified Form of the Operation on the Operands, see `ASTNode::try_simplify`.
+    pub fn simplify(self, lhs: ASTNode, rhs: ASTNode) -> ASTNode {
+        ASTNode::infix(self, lhs, rhs).try_simplify()
+    }
 }
 
 /* --------------------------------------------------------------------------------------------- */
@@ -136,15 +156,21 @@ impl InfixOperator {
 impl Operator for InfixOperator {
     fn assoc(&self) -> Assoc {
         match self {
-            InfixOperator::And | InfixOperator::Or => Assoc::Left,
+            InfixOperator::And
+            | InfixOperator::Or
+            | InfixOperator::Xor
+            | InfixOperator::Equivalence => Assoc::Left,
             InfixOperator::Implication => Assoc::Right,
             _ => unreachable!(),
         }
     }
     fn precedence(&self) -> usize {
         match self {
-            InfixOperator::And | InfixOperator::Or => 2,
+            InfixOperator::And => 4,
+            InfixOperator::Xor => 3,
+            InfixOperator::Or => 2,
             InfixOperator::Implication => 1,
+            InfixOperator::Equivalence => 0,
             _ => unreachable!(),
         }
     }
@@ -154,12 +180,7 @@ impl Operator for InfixOperator {
 
 impl Display for InfixOperator {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            InfixOperator::And => write!(f, "&"),
-            InfixOperator::Or => write!(f, "|"),
-            InfixOperator::Implication => write!(f, "->"),
-            _ => unreachable!(),
-        }
+        write!(f, "{}", self.symbol(Notation::Ascii))
     }
 }
 
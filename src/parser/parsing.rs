@@ -2,19 +2,39 @@
 
 /* --------------------------------------------------------------------------------------------- */
 
+use crate::solver::{
+    espresso,
+    minterm::{product_of_sums, sum_of_products, Minterm, TruthValue},
+    petrick::CoverCost,
+    quine_mccluskey,
+    sat::{self, SatResult},
+};
+
 use crate::parser::{
     ast_node::ASTNode,
-    operators::{InfixOperator, UnaryOperator},
+    binding::Bindings,
+    dialect::{Dialect, Notation},
+    dimacs,
+    error::{EvaluationError, NormalFormError, ParseError},
+    karnaugh_map::KarnaughMap,
+    normal_form::{self, Cnf, Dnf, NormalFormOptions},
+    operators::{Assoc, InfixOperator, Operator, UnaryOperator},
+    printer::Printer,
+    simplifier::{self, RewriteStep},
+    truth_table::TruthTable,
+    tseitin::{Encoding, TseitinCnf},
 };
 
 /* --------------------------------------------------------------------------------------------- */
 
+use pest::error::{Error as PestError, ErrorVariant, InputLocation};
 use pest::iterators::{Pair, Pairs};
 use pest::Parser as ParserTrait;
 use pest_derive::Parser;
 
 /* --------------------------------------------------------------------------------------------- */
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::{Binary, Debug, Display, Formatter};
 
@@ -26,30 +46,48 @@ struct Parser;
 
 /* --------------------------------------------------------------------------------------------- */
 
+/// A parsed Expression together with the Names of its Variables.
+///
+/// `Parsed::new` borrows the Names from the Input so parsing doesn't copy them.
+/// Use `into_owned` to detach the Result from the Input, e.g. to cache it or to send it to
+/// another Thread.
+///
+/// Besides Expressions, the Textbook-Notation "f(a, b, c) = Σm(1, 3, 5) + d(2)" (or "ΠM" for
+/// the false Rows) is accepted. The Variables are numbered in the listed Order, and the
+/// Don't-Cares are kept for `minimize`.
+///
+/// The Operator-Words `and`, `or`, `xor`, `implies`, `iff` and `not` (in any Case) are reserved,
+/// so Variables with these Names have to be quoted, e.g. `"not" & "implies"`.
 #[derive(Clone)]
 pub struct Parsed<'a> {
     expression: ASTNode,
-    var_bindings: Vec<&'a str>,
+    var_bindings: Vec<Cow<'a, str>>,
+    dialect: Dialect,
+    dont_cares: Vec<usize>,
 }
 
 /* --------------------------------------------------------------------------------------------- */
 
 impl<'a> Parsed<'a> {
-    pub fn new(s: &'a str) -> Result<Self, String> {
+    pub fn new(s: &'a str) -> Result<Self, ParseError> {
+        if Parser::parse(Rule::_index_head, s).is_ok() {
+            return parse_index_form(s);
+        }
+
         let mut num_variables: usize = 0;
         // Create local HashMap to quickly check if Identifiers were already known.
-        let mut set: HashMap<&'a str, usize> = HashMap::new();
+        let mut set: HashMap<Cow<'a, str>, usize> = HashMap::new();
         // Store Identifiers in Vector for easier and faster access.
-        let mut map: Vec<&'a str> = Vec::new();
+        let mut map: Vec<Cow<'a, str>> = Vec::new();
 
         // Create Closure which maps the Identifier to the Binding.
-        let mut insert_variable = |var: &'a str| match set.get(var) {
+        let mut insert_variable = |var: Cow<'a, str>| match set.get(&var) {
             // If Identifier is already known return it's Index in the Vector
             Some(idx) => *idx,
             // If the Identifier is not known add it to the Map and Vector and return
             // the corresponding Index.
             None => {
-                set.insert(var, num_variables);
+                set.insert(var.clone(), num_variables);
                 map.push(var);
                 num_variables += 1;
                 num_variables - 1
@@ -60,29 +98,478 @@ impl<'a> Parsed<'a> {
         match Parser::parse(Rule::_start, s) {
             Ok(mut pairs) => {
                 let pair = pairs.next().unwrap();
+                let dialect = detect_dialect(pair.clone().into_inner().flatten());
                 let parsed = parse_expression(pair, &mut insert_variable);
                 // Free the Set because all Variables are bound/mapped.
                 drop(set);
                 Ok(Parsed {
                     expression: parsed,
                     var_bindings: map,
+                    dialect,
+                    dont_cares: Vec::new(),
                 })
             }
-            // Return the Pest-Error if something went wrong
-            Err(e) => Err(e.to_string()),
+            // Translate the Pest-Error if something went wrong
+            Err(e) => Err(convert_error(s, e)),
+        }
+    }
+    /// Copies the Variable-Names so the Result no longer borrows from the Input.
+    pub fn into_owned(self) -> Parsed<'static> {
+        Parsed {
+            expression: self.expression,
+            var_bindings: self
+                .var_bindings
+                .into_iter()
+                .map(|name| Cow::Owned(name.into_owned()))
+                .collect(),
+            dialect: self.dialect,
+            dont_cares: self.dont_cares,
         }
     }
+    /// Dialect the Operators and Constants of the Input were written in.
+    pub fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+    /// Names of the Variables, ordered by their Integer-Identifier.
+    pub fn variable_names(&self) -> impl Iterator<Item = &str> {
+        self.var_bindings.iter().map(|name| name.as_ref())
+    }
     pub fn num_variables(&self) -> usize {
         self.var_bindings.len()
     }
+    pub fn expression(&self) -> &ASTNode {
+        &self.expression
+    }
+    /// Rows of the Truth-Table that were listed as Don't-Cares in the Input.
+    pub fn dont_cares(&self) -> &[usize] {
+        &self.dont_cares
+    }
+    /// Indices of the Rows of the Truth-Table in which the Expression is true, without the
+    /// Don't-Cares.
+    ///
+    /// # Panics
+    /// If there are too many Variables for a Truth-Table, i.e. `usize::BITS` or more.
+    pub fn minterm_indices(&self) -> Vec<usize> {
+        self.truth_table()
+            .true_rows()
+            .map(|row| row.index)
+            .filter(|row| !self.dont_cares.contains(row))
+            .collect()
+    }
+    /// Indices of the Rows of the Truth-Table in which the Expression is false, without the
+    /// Don't-Cares.
+    ///
+    /// # Panics
+    /// If there are too many Variables for a Truth-Table, i.e. `usize::BITS` or more.
+    pub fn maxterm_indices(&self) -> Vec<usize> {
+        self.truth_table()
+            .false_rows()
+            .map(|row| row.index)
+            .filter(|row| !self.dont_cares.contains(row))
+            .collect()
+    }
+    /// The Function in Minterm-Notation, e.g. "f(a, b, c) = Σm(1, 3, 5) + d(2)".
+    pub fn sum_of_minterms(&self, name: &str) -> String {
+        self.index_form(name, "Σm", &self.minterm_indices(), "+")
+    }
+    /// The Function in Maxterm-Notation, e.g. "f(a, b, c) = ΠM(0, 2, 4, 6, 7) · d(2)".
+    pub fn product_of_maxterms(&self, name: &str) -> String {
+        self.index_form(name, "ΠM", &self.maxterm_indices(), "·")
+    }
     pub fn evaluate(&self, binding: &Vec<(usize, bool)>) -> bool {
         self.expression.evaluate(binding)
     }
+    /// Evaluates the Expression with the Values indexed by the Variable-Identifiers,
+    /// i.e. in the Order of `variable_names`.
+    pub fn evaluate_slice(&self, values: &[bool]) -> bool {
+        self.expression.evaluate_slice(values)
+    }
+    /// Evaluates the Expression with the Values of the named Variables.
+    ///
+    /// # Panics
+    /// If a Variable of the Expression has no Value, see `try_evaluate`.
+    pub fn evaluate_with(&self, values: &HashMap<&str, bool>) -> bool {
+        match self.try_evaluate(values) {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Evaluates the Expression with the Values of the named Variables.
+    /// Variables that don't occur in the Expression anymore (e.g. after simplifying) don't
+    /// need a Value.
+    pub fn try_evaluate(&self, values: &HashMap<&str, bool>) -> Result<bool, EvaluationError> {
+        self.expression
+            .try_evaluate(&|i| values.get(self.var_bindings[i].as_ref()).copied())
+            .map_err(|i| EvaluationError::UnboundVariable(self.var_bindings[i].to_string()))
+    }
+    /// Bindings of every Variable to false, to enumerate the Assignments of the Expression.
+    pub fn bindings(&self) -> Bindings<'_> {
+        Bindings::from(self.variable_names().collect::<Vec<&str>>())
+    }
+    /// Evaluates the Expression for every Assignment of its Variables.
+    ///
+    /// # Panics
+    /// If there are too many Variables for a Truth-Table, i.e. `usize::BITS` or more.
+    pub fn truth_table(&self) -> TruthTable {
+        TruthTable::new(
+            &self.expression,
+            self.variable_names().map(String::from).collect(),
+        )
+    }
+    /// Karnaugh-Map of the Expression with the Don't-Cares of the Input marked, None if there
+    /// are more than `KarnaughMap::MAX_VARIABLES` Variables.
+    pub fn karnaugh_map(&self) -> Option<KarnaughMap> {
+        if (self.num_variables() > KarnaughMap::MAX_VARIABLES) {
+            return None;
+        }
+        let table = self.truth_table();
+        let values = table
+            .rows()
+            .map(|row| match self.dont_cares.contains(&row.index) {
+                true => TruthValue::DontCare,
+                false => TruthValue::from(row.output),
+            })
+            .collect();
+        Some(KarnaughMap::new(table.variables().to_vec(), values))
+    }
+    /// Minimal Sum-of-Products Form of the Expression, found with the Quine-McCluskey Method.
+    /// The Variable-Table stays the same, even if some Variables don't occur anymore.
+    pub fn minimize(&self) -> Parsed<'a> {
+        self.minimize_with(CoverCost::default())
+    }
+    /// Like `minimize`, but minimal for the given Cost-Function.
+    pub fn minimize_with(&self, cost: CoverCost) -> Parsed<'a> {
+        let cover = quine_mccluskey::minimize_with(
+            &self.minterm_indices(),
+            &self.dont_cares,
+            self.num_variables(),
+            cost,
+        );
+        self.with_expression(sum_of_products(&cover))
+    }
+    /// Minimal Sum-of-Products Form of a Function that only has to agree with the Expression
+    /// outside of the Don't-Care Conditions and the Don't-Cares of the Input. Rows that are
+    /// both true and Don't-Care count as Don't-Care.
+    pub fn minimize_dont_care(
+        &self,
+        dont_cares: &DontCares<'_>,
+        cost: CoverCost,
+    ) -> Result<Minimized<'a>, EvaluationError> {
+        let dc_rows = self.dont_care_rows(dont_cares)?;
+        let on_set: Vec<usize> = self
+            .truth_table()
+            .true_rows()
+            .map(|r| r.index)
+            .filter(|row| dc_rows.binary_search(row).is_err())
+            .collect();
+        let cover = quine_mccluskey::minimize_with(&on_set, &dc_rows, self.num_variables(), cost);
+        let (dont_cares_true, dont_cares_false) = dc_rows
+            .into_iter()
+            .partition(|row| cover.iter().any(|term| term.covers(*row)));
+        Ok(Minimized {
+            parsed: self.with_expression(sum_of_products(&cover)),
+            dont_cares_true,
+            dont_cares_false,
+        })
+    }
+    /// Small, but not necessarily minimal, Sum-of-Products Form of the Expression, found with
+    /// the Espresso-Heuristic. Unlike `minimize`, this doesn't build the Truth-Table and works
+    /// for Expressions with many Variables.
+    pub fn minimize_heuristic(&self) -> Parsed<'a> {
+        self.minimize_heuristic_dont_care(&DontCares::Indices(Vec::new()))
+            .unwrap()
+            .parsed
+    }
+    /// Like `minimize_heuristic`, but the Result may have any Value in the Rows given by
+    /// `dont_cares`. The Don't-Cares become Cubes directly, so no Truth-Table is built here
+    /// either, but the reported Don't-Care Rows are listed one by one, so a Condition should
+    /// only be true for few Rows. Fails like `minimize_dont_care` for unknown Variables and
+    /// Rows out of Range.
+    pub fn minimize_heuristic_dont_care(
+        &self,
+        dont_cares: &DontCares<'_>,
+    ) -> Result<Minimized<'a>, EvaluationError> {
+        let n = self.num_variables();
+        let row_cubes = |indices: &[usize]| -> Result<Vec<Minterm>, EvaluationError> {
+            indices
+                .iter()
+                .map(|row| match row.checked_shr(n as u32).unwrap_or(0) {
+                    0 => Ok(Minterm::from_index(*row, n)),
+                    _ => Err(EvaluationError::RowOutOfRange(*row)),
+                })
+                .collect()
+        };
+        let mut dc_set = row_cubes(&self.dont_cares)?;
+        match dont_cares {
+            DontCares::Indices(indices) => dc_set.extend(row_cubes(indices)?),
+            DontCares::Expression(condition) => {
+                let mapping = condition
+                    .variable_names()
+                    .map(|name| {
+                        self.variable_names()
+                            .position(|own| own == name)
+                            .ok_or_else(|| EvaluationError::UnboundVariable(name.to_string()))
+                    })
+                    .collect::<Result<Vec<usize>, _>>()?;
+                let renamed = rename(&condition.expression, &mapping);
+                dc_set.extend(espresso::cover(&renamed, true, n));
+            }
+        }
+        let on_set = espresso::cover(&self.expression, true, n);
+        let off_set = espresso::complement(&[on_set.as_slice(), &dc_set].concat(), n);
+        let cover = espresso::minimize(&on_set, &dc_set, &off_set);
+        let mut dc_rows: Vec<usize> = dc_set.iter().flat_map(Minterm::indices).collect();
+        dc_rows.sort_unstable();
+        dc_rows.dedup();
+        let (dont_cares_true, dont_cares_false) = dc_rows
+            .into_iter()
+            .partition(|row| cover.iter().any(|term| term.covers(*row)));
+        Ok(Minimized {
+            parsed: self.with_expression(sum_of_products(&cover)),
+            dont_cares_true,
+            dont_cares_false,
+        })
+    }
+    /// Sorted Indices of the Rows of the Truth-Table that are Don't-Cares.
+    fn dont_care_rows(&self, dont_cares: &DontCares<'_>) -> Result<Vec<usize>, EvaluationError> {
+        let table = self.truth_table();
+        let mut rows = match dont_cares {
+            DontCares::Indices(indices) => {
+                if let Some(row) = indices.iter().find(|row| **row >= table.len()) {
+                    return Err(EvaluationError::RowOutOfRange(*row));
+                }
+                indices.clone()
+            }
+            DontCares::Expression(condition) => {
+                let mut rows = Vec::new();
+                for row in table.rows() {
+                    let values: HashMap<&str, bool> = self
+                        .variable_names()
+                        .zip(row.inputs.iter().copied())
+                        .collect();
+                    if condition.try_evaluate(&values)? {
+                        rows.push(row.index);
+                    }
+                }
+                rows
+            }
+        };
+        rows.extend(self.dont_cares.iter().copied());
+        rows.sort_unstable();
+        rows.dedup();
+        Ok(rows)
+    }
+    fn index_form(&self, name: &str, symbol: &str, indices: &[usize], separator: &str) -> String {
+        let printer = Printer::new();
+        let list = |indices: &[usize]| {
+            indices
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        let variables: Vec<String> = (0..self.num_variables())
+            .map(|i| printer.print(&ASTNode::Identifier(i), &self.var_bindings))
+            .collect();
+        let mut out = format!(
+            "{}({}) = {}({})",
+            name,
+            variables.join(", "),
+            symbol,
+            list(indices)
+        );
+        if (!self.dont_cares.is_empty()) {
+            out.push_str(&format!(" {} d({})", separator, list(&self.dont_cares)));
+        }
+        out
+    }
+    /// Copy of this Parsed with a different Expression over the same Variables.
+    pub(crate) fn with_expression(&self, expression: ASTNode) -> Parsed<'a> {
+        Parsed {
+            expression,
+            var_bindings: self.var_bindings.clone(),
+            dialect: self.dialect,
+            dont_cares: self.dont_cares.clone(),
+        }
+    }
+    /// Same Expression with more Variables after the existing ones. The Don't-Cares are
+    /// dropped, since the Rows of the Truth-Table change.
+    pub(crate) fn with_variables(&self, names: Vec<String>) -> Parsed<'a> {
+        let mut var_bindings = self.var_bindings.clone();
+        var_bindings.extend(names.into_iter().map(Cow::Owned));
+        Parsed {
+            expression: self.expression.clone(),
+            var_bindings,
+            dialect: self.dialect,
+            dont_cares: Vec::new(),
+        }
+    }
+    /// Prints the Expression with the given Printer, e.g. to limit the Line-Width.
+    pub fn print(&self, printer: &Printer) -> String {
+        printer.print(&self.expression, &self.var_bindings)
+    }
+    /// Prints the Expression in the given Notation, e.g. `parsed.display_with(Notation::Unicode)`.
+    pub fn display_with(&self, notation: Notation) -> DisplayWith<'_, 'a> {
+        DisplayWith {
+            parsed: self,
+            notation,
+        }
+    }
     pub fn try_simplify(mut self) -> Self {
         // Recursively simplify Expressions
         self.expression = self.expression.try_simplify();
         self
     }
+    /// Like `try_simplify`, but also returns every Rewrite that led to the Result, in Order,
+    /// see `render_derivation`.
+    pub fn try_simplify_traced(mut self) -> (Self, Vec<RewriteStep>) {
+        let (expression, steps) = self.expression.try_simplify_traced();
+        self.expression = expression;
+        (self, steps)
+    }
+    /// Numbered Derivation of the Steps with the Names of the Variables, one Line per Step
+    /// with the Law that was applied and where. Call it on the Result of `try_simplify_traced`.
+    pub fn render_derivation(&self, steps: &[RewriteStep]) -> String {
+        simplifier::render_derivation(&self.expression, steps, &self.var_bindings)
+    }
+    /// Equivalent Expression in Negation-Normal-Form, see `ASTNode::to_nnf`.
+    pub fn to_nnf(&self) -> Parsed<'a> {
+        self.with_expression(self.expression.to_nnf())
+    }
+    /// Clauses of the Conjunctive Normal Form of the Expression, see `ASTNode::to_cnf`.
+    /// Canonical Forms range over every Variable, including ones that don't occur.
+    pub fn to_cnf(&self, options: NormalFormOptions) -> Result<Cnf, NormalFormError> {
+        normal_form::cnf(&self.expression, self.num_variables(), options)
+    }
+    /// Cubes of the Disjunctive Normal Form of the Expression, see `ASTNode::to_dnf`.
+    /// Canonical Forms range over every Variable, including ones that don't occur.
+    pub fn to_dnf(&self, options: NormalFormOptions) -> Result<Dnf, NormalFormError> {
+        normal_form::dnf(&self.expression, self.num_variables(), options)
+    }
+    /// The Conjunctive Normal Form of `to_cnf` as an Expression with the same Variables.
+    pub fn to_cnf_expression(
+        &self,
+        options: NormalFormOptions,
+    ) -> Result<Parsed<'a>, NormalFormError> {
+        Ok(self.with_expression(self.to_cnf(options)?.to_ast()))
+    }
+    /// The Disjunctive Normal Form of `to_dnf` as an Expression with the same Variables.
+    pub fn to_dnf_expression(
+        &self,
+        options: NormalFormOptions,
+    ) -> Result<Parsed<'a>, NormalFormError> {
+        Ok(self.with_expression(self.to_dnf(options)?.to_ast()))
+    }
+    /// Equisatisfiable CNF with an auxiliary Variable per Subformula, linear in the Size of
+    /// the Expression. The auxiliary Variables are added after the Variables of the Expression.
+    pub fn tseitin(&self, encoding: Encoding) -> TseitinCnf<'a> {
+        TseitinCnf::new(self, encoding)
+    }
+    /// Looks for an Assignment the Expression is true for with the CDCL-Solver, working on the
+    /// Plaisted-Greenbaum Encoding instead of the Truth-Table. A Model has the Value of every
+    /// Variable of the Expression.
+    pub fn solve(&self) -> SatResult {
+        let encoded = self.tseitin(Encoding::PlaistedGreenbaum);
+        match sat::solve(encoded.cnf(), encoded.num_variables()) {
+            SatResult::Sat(mut model) => {
+                model.truncate(self.num_variables());
+                SatResult::Sat(model)
+            }
+            SatResult::Unsat => SatResult::Unsat,
+        }
+    }
+    /// Checks if the Expression is true for some Assignment, with `solve`, and names one.
+    pub fn is_satisfiable(&self) -> Satisfiability {
+        match self.satisfying(self.expression.clone()) {
+            Some(assignment) => Satisfiability::Satisfiable(assignment),
+            None => Satisfiability::Unsatisfiable,
+        }
+    }
+    /// Checks if the Expression is true for every Assignment, the Counterexample is an
+    /// Assignment it is false for.
+    pub fn is_tautology(&self) -> Verdict {
+        Verdict::unless(self.satisfying(self.expression.clone().negate()))
+    }
+    /// Checks if the Expression is false for every Assignment, the Counterexample is an
+    /// Assignment it is true for.
+    pub fn is_contradiction(&self) -> Verdict {
+        Verdict::unless(self.satisfying(self.expression.clone()))
+    }
+    /// Checks if both Expressions agree on every Assignment. Variables are matched by Name, the
+    /// Counterexample assigns the Variables of both.
+    pub fn equivalent(&self, other: &Parsed<'_>) -> Verdict {
+        let (unified, other) = self.unify(other);
+        let differ = ASTNode::infix(InfixOperator::Xor, unified.expression.clone(), other);
+        Verdict::unless(unified.satisfying(differ))
+    }
+    /// Checks if the other Expression is true for every Assignment this one is true for.
+    /// Variables are matched by Name, the Counterexample assigns the Variables of both.
+    pub fn implies(&self, other: &Parsed<'_>) -> Verdict {
+        let (unified, other) = self.unify(other);
+        let violated = ASTNode::infix(
+            InfixOperator::And,
+            unified.expression.clone(),
+            other.negate(),
+        );
+        Verdict::unless(unified.satisfying(violated))
+    }
+    /// Some Assignment of the Variables the Condition is true for.
+    fn satisfying(&self, condition: ASTNode) -> Option<Assignment> {
+        match self.with_expression(condition).solve() {
+            SatResult::Sat(model) => Some(Assignment {
+                values: self
+                    .variable_names()
+                    .map(str::to_string)
+                    .zip(model)
+                    .collect(),
+            }),
+            SatResult::Unsat => None,
+        }
+    }
+    /// This Expression with the Variables of the other one that it doesn't have, and the
+    /// other Expression renumbered to these Variables.
+    fn unify(&self, other: &Parsed<'_>) -> (Parsed<'a>, ASTNode) {
+        let mut names: Vec<String> = Vec::new();
+        let mapping: Vec<usize> = other
+            .variable_names()
+            .map(|name| match self.variable_names().position(|n| n == name) {
+                Some(idx) => idx,
+                None => {
+                    names.push(name.to_string());
+                    self.num_variables() + names.len() - 1
+                }
+            })
+            .collect();
+        (
+            self.with_variables(names),
+            rename(&other.expression, &mapping),
+        )
+    }
+    /// Writes the Expression as DIMACS-CNF, with a Comment "c var <number> <name>" for every
+    /// Variable. Conjunctions of Clauses are written as they are, other Expressions as their
+    /// Plaisted-Greenbaum Encoding, whose auxiliary Variables are listed after the others.
+    pub fn to_dimacs(&self) -> String {
+        match normal_form::as_cnf(&self.expression) {
+            Some(cnf) => dimacs::write(&cnf, &self.var_bindings),
+            None => {
+                let encoded = self.tseitin(Encoding::PlaistedGreenbaum);
+                let names: Vec<&str> = encoded.parsed().variable_names().collect();
+                dimacs::write(encoded.cnf(), &names)
+            }
+        }
+    }
+    /// Reads a DIMACS-CNF File as the Conjunction of its Clauses. Variables are named by the
+    /// "c var <number> <name>" Comments `to_dimacs` writes, or "x<number>" without one.
+    /// Duplicate Names and Headers with more than 2^20 Variables are rejected.
+    pub fn from_dimacs(source: &str) -> Result<Parsed<'static>, ParseError> {
+        let (cnf, names) = dimacs::read(source)?;
+        Ok(Parsed {
+            expression: cnf.to_ast(),
+            var_bindings: names.into_iter().map(Cow::Owned).collect(),
+            dialect: Dialect::default(),
+            dont_cares: Vec::new(),
+        })
+    }
 }
 
 /* --------------------------------------------------------------------------------------------- */
@@ -119,11 +606,199 @@ impl<'a> Display for Parsed<'a> {
     }
 }
 
+/// Don't-Care Conditions for `Parsed::minimize_dont_care`.
+#[derive(Debug, Clone)]
+pub enum DontCares<'d> {
+    /// Indices of Rows of the Truth-Table, the first Variable is the most significant Bit.
+    Indices(Vec<usize>),
+    /// The Rows in which this Expression is true. Its Variables are matched by Name and all
+    /// of them have to occur in the Expression that is minimized.
+    Expression(&'d Parsed<'d>),
+}
+
+/// Result of `Parsed::minimize_dont_care` and `Parsed::minimize_heuristic_dont_care`.
+#[derive(Debug, Clone)]
+pub struct Minimized<'a> {
+    pub parsed: Parsed<'a>,
+    /// Don't-Care Rows the minimized Expression is true for.
+    pub dont_cares_true: Vec<usize>,
+    /// Don't-Care Rows the minimized Expression is false for.
+    pub dont_cares_false: Vec<usize>,
+}
+
+/// Result of a Check like `Parsed::is_tautology`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Holds,
+    /// The Check fails for the Assignment.
+    Fails(Assignment),
+}
+
+/// Result of `Parsed::is_satisfiable`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Satisfiability {
+    /// The Expression is true for the Assignment.
+    Satisfiable(Assignment),
+    Unsatisfiable,
+}
+
+/// Values of named Variables, a Counterexample of a failed Check or a Model of a satisfiable
+/// Expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub values: Vec<(String, bool)>,
+}
+
+impl Verdict {
+    pub fn holds(&self) -> bool {
+        matches!(self, Verdict::Holds)
+    }
+    /// The Assignment the Check fails for, if there is one.
+    pub fn counterexample(&self) -> Option<&Assignment> {
+        match self {
+            Verdict::Fails(counterexample) => Some(counterexample),
+            Verdict::Holds => None,
+        }
+    }
+    /// Holds unless there is a Counterexample.
+    fn unless(counterexample: Option<Assignment>) -> Self {
+        match counterexample {
+            Some(counterexample) => Verdict::Fails(counterexample),
+            None => Verdict::Holds,
+        }
+    }
+}
+
+impl Satisfiability {
+    pub fn is_satisfiable(&self) -> bool {
+        matches!(self, Satisfiability::Satisfiable(_))
+    }
+    /// The satisfying Assignment, if there is one.
+    pub fn assignment(&self) -> Option<&Assignment> {
+        match self {
+            Satisfiability::Satisfiable(assignment) => Some(assignment),
+            Satisfiability::Unsatisfiable => None,
+        }
+    }
+}
+
+impl Assignment {
+    /// Value of the named Variable.
+    pub fn get(&self, name: &str) -> Option<bool> {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Prints the Assignment like "a = 1, b = 0".
+impl Display for Assignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|(name, value)| format!("{} = {}", name, *value as u8))
+            .collect();
+        write!(f, "{}", values.join(", "))
+    }
+}
+
+/// Displays a Parsed Expression in a specific Notation, see `Parsed::display_with`.
+pub struct DisplayWith<'p, 'a> {
+    parsed: &'p Parsed<'a>,
+    notation: Notation,
+}
+
+impl<'p, 'a> Display for DisplayWith<'p, 'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let printer = Printer::new().with_notation(self.notation);
+        write!(f, "{}", self.parsed.print(&printer))
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Copy of the Expression with every Integer-Identifier `i` replaced by `mapping[i]`.
+fn rename(node: &ASTNode, mapping: &[usize]) -> ASTNode {
+    match node {
+        ASTNode::Identifier(i) => ASTNode::Identifier(mapping[*i]),
+        ASTNode::Literal(l) => ASTNode::Literal(*l),
+        ASTNode::UnaryOperation { op, expr } => ASTNode::UnaryOperation {
+            op: *op,
+            expr: Box::new(rename(expr, mapping)),
+        },
+        ASTNode::InfixOperation { op, lhs, rhs } => {
+            ASTNode::infix(*op, rename(lhs, mapping), rename(rhs, mapping))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn detect_dialect(pairs: pest::iterators::FlatPairs<Rule>) -> Dialect {
+    pairs
+        .filter(|pair| {
+            matches!(
+                pair.as_rule(),
+                Rule::OperationEquivalence
+                    | Rule::OperationImplication
+                    | Rule::OperationOr
+                    | Rule::OperationXor
+                    | Rule::OperationAnd
+                    | Rule::OperationNot
+                    | Rule::Literal
+            )
+        })
+        .filter_map(|pair| Dialect::of_token(pair.as_str()))
+        .reduce(Dialect::combine)
+        .unwrap_or_default()
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+fn convert_error(source: &str, e: PestError<Rule>) -> ParseError {
+    let pos = match e.location {
+        InputLocation::Pos(pos) => pos,
+        InputLocation::Span((start, _)) => start,
+    };
+    let mut expected: Vec<String> = Vec::new();
+    if let ErrorVariant::ParsingError { positives, .. } = e.variant {
+        for rule in positives {
+            let token = describe_rule(rule).to_string();
+            if !expected.contains(&token) {
+                expected.push(token);
+            }
+        }
+    }
+    ParseError::at(source, pos, expected)
+}
+
+/// Name of the Token a Rule stands for, used in Error-Messages.
+fn describe_rule(rule: Rule) -> &'static str {
+    match rule {
+        Rule::OperationEquivalence => "`<->`",
+        Rule::OperationImplication => "`->`",
+        Rule::OperationOr => "`|`",
+        Rule::OperationXor => "`^`",
+        Rule::OperationAnd => "`&`",
+        Rule::OperationNot | Rule::UnaryOperation => "`!`",
+        Rule::ClosingParenthesis => "`)`",
+        Rule::Identifier | Rule::PlainIdentifier | Rule::QuotedIdentifier => "identifier",
+        Rule::Literal => "literal",
+        Rule::EOI => "end of input",
+        Rule::MintermSymbol => "`Σm`",
+        Rule::MaxtermSymbol => "`ΠM`",
+        Rule::DontCareList => "`d`",
+        Rule::Index => "index",
+        _ => "expression",
+    }
+}
+
 /* --------------------------------------------------------------------------------------------- */
 
 fn parse_expression<'a, F>(pair: Pair<'a, Rule>, i: &mut F) -> ASTNode
 where
-    F: FnMut(&'a str) -> usize,
+    F: FnMut(Cow<'a, str>) -> usize,
 {
     match pair.as_rule() {
         Rule::Expression => parse_expression(pair.into_inner().next().unwrap(), i),
@@ -135,8 +810,11 @@ where
                 _ => unreachable!(),
             }
         }
-        Rule::ImplicationExpression => parse_right_associtiv(pair.into_inner(), i),
-        Rule::AndOrExpression => parse_left_associativ(pair.into_inner(), i),
+        Rule::EquivalenceExpression
+        | Rule::ImplicationExpression
+        | Rule::OrExpression
+        | Rule::XorExpression
+        | Rule::AndExpression => parse_infix_expression(pair.into_inner(), i),
         Rule::UnaryExpression => parse_unary_expression(pair.into_inner(), i),
         Rule::Variable => parse_variable(pair.into_inner().next().unwrap(), i),
         _ => unreachable!(),
@@ -147,18 +825,29 @@ where
 
 fn parse_infix_operator<'a, F>(op: &Pair<Rule>, i: &mut F) -> InfixOperator
 where
-    F: FnMut(&'a str) -> usize,
+    F: FnMut(Cow<'a, str>) -> usize,
 {
     match op.as_str() {
-        "&" | "*" => InfixOperator::And,
-        "|" | "+" => InfixOperator::Or,
-        "->" => InfixOperator::Implication,
+        "&" | "*" | "∧" | "\\land" | "\\wedge" => InfixOperator::And,
+        "|" | "+" | "∨" | "\\lor" | "\\vee" => InfixOperator::Or,
+        "^" | "⊕" | "\\oplus" | "\\veebar" => InfixOperator::Xor,
+        "->" | "→" | "⇒" | "\\rightarrow" | "\\Rightarrow" | "\\implies" | "\\to" => {
+            InfixOperator::Implication
+        }
+        "<->" | "<=>" | "↔" | "⇔" | "\\leftrightarrow" | "\\Leftrightarrow" | "\\iff"
+        | "\\equiv" => InfixOperator::Equivalence,
         _ => {
             // Use eq_ignore_ascii_case to avoid unnecessary Allocations.
             if (op.as_str().eq_ignore_ascii_case("and")) {
                 InfixOperator::And
             } else if (op.as_str().eq_ignore_ascii_case("or")) {
                 InfixOperator::Or
+            } else if (op.as_str().eq_ignore_ascii_case("xor")) {
+                InfixOperator::Xor
+            } else if (op.as_str().eq_ignore_ascii_case("implies")) {
+                InfixOperator::Implication
+            } else if (op.as_str().eq_ignore_ascii_case("iff")) {
+                InfixOperator::Equivalence
             } else {
                 unreachable!()
             }
@@ -168,9 +857,28 @@ where
 
 /* --------------------------------------------------------------------------------------------- */
 
+/// Parses a Chain of Operators from the same Precedence-Level.
+/// All Operators of a Level share their Associativity so the first one decides how the
+/// whole Chain is folded.
+fn parse_infix_expression<'a, F>(pair: Pairs<'a, Rule>, i: &mut F) -> ASTNode
+where
+    F: FnMut(Cow<'a, str>) -> usize,
+{
+    match pair
+        .clone()
+        .nth(1)
+        .map(|op| parse_infix_operator(&op, i).assoc())
+    {
+        Some(Assoc::Right) => parse_right_associtiv(pair, i),
+        _ => parse_left_associativ(pair, i),
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
 fn parse_right_associtiv<'a, F>(mut pair: Pairs<'a, Rule>, i: &mut F) -> ASTNode
 where
-    F: FnMut(&'a str) -> usize,
+    F: FnMut(Cow<'a, str>) -> usize,
 {
     let mut lhs = parse_expression(pair.next().unwrap(), i);
     match pair.peek() {
@@ -181,15 +889,15 @@ where
 
 fn _parse_right_associtiv<'a, F>(lhs: ASTNode, mut pair: Pairs<'a, Rule>, i: &mut F) -> ASTNode
 where
-    F: FnMut(&'a str) -> usize,
+    F: FnMut(Cow<'a, str>) -> usize,
 {
     let mut lhs = lhs;
     match (pair.next(), pair.next()) {
-        (Some(op), Some(rhs)) => ASTNode::InfixOperation {
-            op: parse_infix_operator(&op, i),
-            lhs: Box::new(lhs),
-            rhs: Box::new(_parse_right_associtiv(parse_expression(rhs, i), pair, i)),
-        },
+        (Some(op), Some(rhs)) => ASTNode::infix(
+            parse_infix_operator(&op, i),
+            lhs,
+            _parse_right_associtiv(parse_expression(rhs, i), pair, i),
+        ),
         _ => lhs,
     }
 }
@@ -198,7 +906,7 @@ where
 
 fn parse_left_associativ<'a, F>(mut pair: Pairs<'a, Rule>, i: &mut F) -> ASTNode
 where
-    F: FnMut(&'a str) -> usize,
+    F: FnMut(Cow<'a, str>) -> usize,
 {
     let mut lhs = parse_expression(pair.next().unwrap(), i);
     match pair.peek() {
@@ -209,15 +917,11 @@ where
 
 fn _parse_left_associativ<'a, F>(lhs: ASTNode, mut pair: Pairs<'a, Rule>, i: &mut F) -> ASTNode
 where
-    F: FnMut(&'a str) -> usize,
+    F: FnMut(Cow<'a, str>) -> usize,
 {
     let mut lhs = lhs;
     while let (Some(op), Some(rhs)) = (pair.next(), pair.next()) {
-        lhs = ASTNode::InfixOperation {
-            op: parse_infix_operator(&op, i),
-            lhs: Box::new(lhs),
-            rhs: Box::new(parse_expression(rhs, i)),
-        };
+        lhs = ASTNode::infix(parse_infix_operator(&op, i), lhs, parse_expression(rhs, i));
     }
     lhs
 }
@@ -226,14 +930,16 @@ where
 
 fn parse_unary_operator(op: &Pair<Rule>) -> UnaryOperator {
     match op.as_str() {
-        "!" | "~" => UnaryOperator::Not,
+        "!" | "~" | "¬" | "\\neg" | "\\lnot" => UnaryOperator::Not,
+        // Use eq_ignore_ascii_case to avoid unnecessary Allocations.
+        s if s.eq_ignore_ascii_case("not") => UnaryOperator::Not,
         _ => unreachable!(),
     }
 }
 
 fn parse_unary_expression<'a, F>(mut pair: Pairs<'a, Rule>, i: &mut F) -> ASTNode
 where
-    F: FnMut(&'a str) -> usize,
+    F: FnMut(Cow<'a, str>) -> usize,
 {
     match (pair.next(), pair.next()) {
         (Some(op), Some(expr)) => ASTNode::UnaryOperation {
@@ -249,7 +955,7 @@ where
 
 fn parse_variable<'a, F>(pair: Pair<'a, Rule>, i: &mut F) -> ASTNode
 where
-    F: FnMut(&'a str) -> usize,
+    F: FnMut(Cow<'a, str>) -> usize,
 {
     match pair.as_rule() {
         Rule::Literal => {
@@ -258,13 +964,17 @@ where
                 ASTNode::Literal(true)
             } else if pair.as_str().eq_ignore_ascii_case("false") {
                 ASTNode::Literal(false)
+            } else if matches!(pair.as_str(), "⊤" | "\\top") {
+                ASTNode::Literal(true)
+            } else if matches!(pair.as_str(), "⊥" | "\\bot") {
+                ASTNode::Literal(false)
             } else {
                 unreachable!()
             }
         }
         Rule::Identifier => {
             // Pass String-Identifier up to Parent and get Int-Identifier back
-            let id = i(pair.as_str());
+            let id = i(identifier_name(pair));
             ASTNode::Identifier(id)
         }
         Rule::Variable => parse_variable(pair.into_inner().next().unwrap(), i),
@@ -273,3 +983,113 @@ where
 }
 
 /* --------------------------------------------------------------------------------------------- */
+fn identifier_name(pair: Pair<'_, Rule>) -> Cow<'_, str> {
+    let inner = pair.into_inner().next().unwrap();
+    match inner.as_rule() {
+        Rule::PlainIdentifier => Cow::Borrowed(inner.as_str()),
+        // Strip the Quotes and keep only the Name itself, a doubled Quote stands for one
+        Rule::QuotedIdentifier => {
+            let name = inner.into_inner().next().unwrap();
+            let quote = match name.as_rule() {
+                Rule::DoubleQuotedName => "\"",
+                Rule::BacktickQuotedName => "`",
+                _ => unreachable!(),
+            };
+            let name = name.as_str();
+            if name.contains(quote) {
+                Cow::Owned(name.replace(&quote.repeat(2), quote))
+            } else {
+                Cow::Borrowed(name)
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Most Variables of the Textbook-Notation, so that every Row-Index fits into a `usize`.
+const MAX_INDEX_VARIABLES: usize = usize::BITS as usize - 1;
+
+/// Parses the Textbook-Notation "f(a, b, c) = Σm(1, 3, 5) + d(2)" into the canonical Sum of
+/// Products (or Product of Sums for "ΠM") of the listed Rows.
+fn parse_index_form(s: &str) -> Result<Parsed<'_>, ParseError> {
+    let form = Parser::parse(Rule::_index_start, s)
+        .map_err(|e| convert_error(s, e))?
+        .next()
+        .unwrap();
+    let mut parts = form.into_inner();
+
+    // The Name of the Function itself isn't needed
+    let mut names: Vec<Cow<'_, str>> = Vec::new();
+    for identifier in parts.next().unwrap().into_inner().skip(1) {
+        let pos = identifier.as_span().start();
+        let name = identifier_name(identifier);
+        if (names.len() == MAX_INDEX_VARIABLES) {
+            let expected = vec![format!("`)` after {} variables", MAX_INDEX_VARIABLES)];
+            return Err(ParseError::at(s, pos, expected));
+        }
+        if names.contains(&name) {
+            let expected = vec!["distinct variable".to_string()];
+            return Err(ParseError::at(s, pos, expected));
+        }
+        names.push(name);
+    }
+    let num_variables = names.len();
+    let num_rows = 1usize << num_variables;
+
+    let mut listed: Vec<usize> = Vec::new();
+    let mut parse_indices = |list: Pair<'_, Rule>| -> Result<Vec<usize>, ParseError> {
+        let mut indices = Vec::new();
+        for index in list.into_inner().filter(|p| p.as_rule() == Rule::Index) {
+            let pos = index.as_span().start();
+            let value = index
+                .as_str()
+                .parse::<usize>()
+                .ok()
+                .filter(|i| *i < num_rows);
+            match value {
+                Some(value) if !listed.contains(&value) => {
+                    listed.push(value);
+                    indices.push(value);
+                }
+                Some(_) => {
+                    let expected = vec!["index that wasn't listed before".to_string()];
+                    return Err(ParseError::at(s, pos, expected));
+                }
+                None => {
+                    let expected = vec![format!("index below {}", num_rows)];
+                    return Err(ParseError::at(s, pos, expected));
+                }
+            }
+        }
+        indices.sort_unstable();
+        Ok(indices)
+    };
+
+    let terms = parts.next().unwrap();
+    let rule = terms.as_rule();
+    let indices = parse_indices(terms)?;
+    let dont_cares = match parts.next() {
+        Some(list) => parse_indices(list)?,
+        None => Vec::new(),
+    };
+    let terms: Vec<Minterm> = indices
+        .iter()
+        .map(|idx| Minterm::from_index(*idx, num_variables))
+        .collect();
+    let expression = match rule {
+        Rule::MintermList => sum_of_products(&terms),
+        Rule::MaxtermList => product_of_sums(&terms),
+        _ => unreachable!(),
+    };
+
+    Ok(Parsed {
+        expression,
+        var_bindings: names,
+        dialect: Dialect::default(),
+        dont_cares,
+    })
+}
+
+/* --------------------------------------------------------------------------------------------- */
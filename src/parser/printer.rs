@@ -0,0 +1,248 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::{
+    ast_node::{fmt_identifier, ASTNode},
+    dialect::Notation,
+    operators::{Assoc, InfixOperator, Operator, UnaryOperator},
+};
+
+use std::fmt::{Display, Formatter};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Pretty-Printer that only emits the Braces required by the Precedence and Associativity of
+/// the Operators, so the printed Expression parses back to the same Tree.
+///
+/// With a maximum Width, Conjunctions and Disjunctions that don't fit on a Line are broken
+/// into one Operand per Line, with the Operator in front of every following Operand.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Printer {
+    notation: Notation,
+    max_width: Option<usize>,
+    /// Writes Names as escaped `\text{}` for LaTeX-Documents instead of parseable Identifiers.
+    latex_names: bool,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Printer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+    pub(crate) fn with_latex_names(mut self) -> Self {
+        self.latex_names = true;
+        self
+    }
+    /// Prints the Expression using the provided Bindings from Integer-Identifier to
+    /// Identifier-Name.
+    pub fn print<S: AsRef<str>>(&self, expression: &ASTNode, bindings: &[S]) -> String {
+        let names: Vec<&str> = bindings.iter().map(AsRef::as_ref).collect();
+        let mut out = String::new();
+        self.write(expression, Some(&names), 0, &mut out);
+        out
+    }
+    /// Prints the Expression with the Integer-Identifiers instead of Names.
+    pub(crate) fn print_numbered(&self, expression: &ASTNode) -> String {
+        let mut out = String::new();
+        self.write(expression, None, 0, &mut out);
+        out
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Printer {
+    /// Precedence and Associativity of the Operator as it is printed.
+    /// C binds "==" and "!=" tighter than "&&" and writes Implications as "!a || b",
+    /// every other Notation follows the Operator-Table of the Grammar.
+    fn binding(&self, op: InfixOperator) -> (usize, Assoc) {
+        match (self.notation, op) {
+            (Notation::CStyle, InfixOperator::Xor | InfixOperator::Equivalence) => {
+                (InfixOperator::And.precedence() + 1, Assoc::Left)
+            }
+            (Notation::CStyle, InfixOperator::Implication) => {
+                (InfixOperator::Or.precedence(), Assoc::Left)
+            }
+            (_, op) => (op.precedence(), op.assoc()),
+        }
+    }
+    fn needs_braces(&self, parent: InfixOperator, child: &ASTNode, is_rhs: bool) -> bool {
+        match child {
+            ASTNode::InfixOperation { op, .. } => {
+                let (parent_precedence, parent_assoc) = self.binding(parent);
+                let (child_precedence, _) = self.binding(*op);
+                child_precedence < parent_precedence
+                    || (child_precedence == parent_precedence
+                        && match parent_assoc {
+                            Assoc::Left => is_rhs,
+                            Assoc::Right => !is_rhs,
+                            Assoc::NotApplicable => true,
+                        })
+            }
+            _ => false,
+        }
+    }
+    fn write(&self, node: &ASTNode, names: Option<&[&str]>, column: usize, out: &mut String) {
+        let flat = self.flat(node, names);
+        match (self.max_width, node) {
+            (
+                Some(max_width),
+                ASTNode::InfixOperation {
+                    op: op @ (InfixOperator::And | InfixOperator::Or),
+                    ..
+                },
+            ) if column + flat.chars().count() > max_width => {
+                self.write_broken(*op, node, names, column, out)
+            }
+            _ => out.push_str(&flat),
+        }
+    }
+    /// Writes a Chain of the same Operator with one Operand per Line.
+    fn write_broken(
+        &self,
+        op: InfixOperator,
+        node: &ASTNode,
+        names: Option<&[&str]>,
+        column: usize,
+        out: &mut String,
+    ) {
+        let mut operands = Vec::new();
+        collect_chain(op, node, &mut operands);
+        let symbol = op.symbol(self.notation);
+        // Every Operand is indented past the Operator, also the first one, so that its own
+        // Line-Breaks can't be confused with the ones of this Chain.
+        let operand_column = column + symbol.chars().count() + 1;
+        for (idx, operand) in operands.into_iter().enumerate() {
+            if (idx > 0) {
+                out.push('\n');
+                out.push_str(&" ".repeat(column));
+                out.push_str(symbol);
+                out.push(' ');
+            }
+            if self.needs_braces(op, operand, idx > 0) {
+                out.push('(');
+                self.write(operand, names, operand_column + 1, out);
+                out.push(')');
+            } else {
+                self.write(operand, names, operand_column, out);
+            }
+        }
+    }
+    /// Renders the Expression on a single Line.
+    fn flat(&self, node: &ASTNode, names: Option<&[&str]>) -> String {
+        match node {
+            ASTNode::InfixOperation { op, lhs, rhs } => {
+                let mut lhs_str = self.flat_operand(*op, lhs, false, names);
+                // C has no Implication, so "a -> b" is written as "!a || b"
+                if (*op == InfixOperator::Implication && self.notation == Notation::CStyle) {
+                    lhs_str = format!(
+                        "{}{}",
+                        UnaryOperator::Not.symbol(self.notation),
+                        self.flat_unary_operand(lhs, names)
+                    );
+                }
+                let rhs_str = self.flat_operand(*op, rhs, true, names);
+                format!("{} {} {}", lhs_str, op.symbol(self.notation), rhs_str)
+            }
+            ASTNode::UnaryOperation { op, expr } => {
+                // Words and LaTeX-Commands have to be separated from the Operand
+                let separator = match self.notation {
+                    Notation::Word | Notation::Latex => " ",
+                    _ => "",
+                };
+                format!(
+                    "{}{}{}",
+                    op.symbol(self.notation),
+                    separator,
+                    self.flat_unary_operand(expr, names)
+                )
+            }
+            ASTNode::Identifier(i) => match names {
+                Some(names) if self.latex_names => latex_identifier(names[*i]),
+                Some(names) => Identifier(names[*i]).to_string(),
+                None => i.to_string(),
+            },
+            ASTNode::Literal(l) => self.notation.literal(*l).to_string(),
+            _ => unreachable!(),
+        }
+    }
+    fn flat_operand(
+        &self,
+        parent: InfixOperator,
+        child: &ASTNode,
+        is_rhs: bool,
+        names: Option<&[&str]>,
+    ) -> String {
+        if self.needs_braces(parent, child, is_rhs) {
+            format!("({})", self.flat(child, names))
+        } else {
+            self.flat(child, names)
+        }
+    }
+    /// Unary Operators bind tighter than every Infix-Operator.
+    fn flat_unary_operand(&self, child: &ASTNode, names: Option<&[&str]>) -> String {
+        match child {
+            ASTNode::InfixOperation { .. } => format!("({})", self.flat(child, names)),
+            _ => self.flat(child, names),
+        }
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Collects the Operands of a Chain of the same Operator that can be printed without Braces.
+fn collect_chain<'n>(op: InfixOperator, node: &'n ASTNode, operands: &mut Vec<&'n ASTNode>) {
+    match node {
+        ASTNode::InfixOperation { op: o, lhs, rhs } if *o == op => match op.assoc() {
+            Assoc::Right => {
+                operands.push(lhs);
+                collect_chain(op, rhs, operands);
+            }
+            _ => {
+                collect_chain(op, lhs, operands);
+                operands.push(rhs);
+            }
+        },
+        _ => operands.push(node),
+    }
+}
+
+/// Identifier-Name as LaTeX-Text, with the Characters escaped that LaTeX treats specially.
+fn latex_identifier(name: &str) -> String {
+    let mut out = String::from("\\text{");
+    for c in name.chars() {
+        match c {
+            '\\' => out.push_str("\\textbackslash{}"),
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            '_' | '&' | '%' | '#' | '$' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    out.push('}');
+    out
+}
+
+/// Wrapper to print an Identifier-Name through `fmt_identifier`.
+struct Identifier<'n>(&'n str);
+
+impl<'n> Display for Identifier<'n> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_identifier(self.0, f)
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -0,0 +1,564 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::{
+    ast_node::ASTNode,
+    operators::{InfixOperator, UnaryOperator},
+    printer::Printer,
+};
+
+use std::fmt::{Display, Formatter};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Laws of Boolean Algebra the Simplifier rewrites with.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Law {
+    /// !!x = x, and !x ^ !y = x ^ y
+    DoubleNegation,
+    /// Constants that don't change the Result: x & true = x, x | false = x, x ^ false = x, ...
+    Identity,
+    /// Constants that decide the Result: x & false = false, x | true = true, x -> true = true
+    Domination,
+    /// x & x = x, x | x = x
+    Idempotence,
+    /// x -> x = true, x <-> x = true, x ^ x = false
+    Reflexivity,
+    /// x & !x = false, x | !x = true, !true = false, x ^ true = !x, ...
+    Complement,
+    /// x | (x & y) = x, x & (x | y) = x
+    Absorption,
+    /// !(x & y) = !x | !y, !(x | y) = !x & !y
+    DeMorgan,
+    /// (x & y) | (!x & z) | (y & z) = (x & y) | (!x & z), and its Dual
+    Consensus,
+}
+
+/// A single Rewrite of `ASTNode::try_simplify_traced`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewriteStep {
+    pub law: Law,
+    /// Path from the Root to the rewritten Subterm, see `RewriteStep::subterm_before`.
+    pub path: Vec<usize>,
+    /// Whole Expression before the Rewrite.
+    pub before: ASTNode,
+    /// Whole Expression after the Rewrite.
+    pub after: ASTNode,
+}
+
+type Rule = fn(&ASTNode) -> Option<ASTNode>;
+
+/// Every Rule makes the Expression strictly smaller, so rewriting always terminates.
+const RULES: [(Law, Rule); 12] = [
+    (Law::Complement, complement_of_literal),
+    (Law::DoubleNegation, double_negation),
+    (Law::DeMorgan, de_morgan_inwards),
+    (Law::Identity, identity),
+    (Law::Domination, domination),
+    (Law::Complement, complement_of_constant_operand),
+    (Law::Idempotence, idempotence),
+    (Law::Reflexivity, reflexivity),
+    (Law::Complement, complement),
+    (Law::Absorption, absorption),
+    (Law::Consensus, consensus),
+    (Law::DeMorgan, de_morgan_outwards),
+];
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Law {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Law::DoubleNegation => "double negation",
+            Law::Identity => "identity",
+            Law::Domination => "domination",
+            Law::Idempotence => "idempotence",
+            Law::Reflexivity => "reflexivity",
+            Law::Complement => "complement",
+            Law::Absorption => "absorption",
+            Law::DeMorgan => "De Morgan",
+            Law::Consensus => "consensus",
+        }
+    }
+}
+
+impl Display for Law {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl RewriteStep {
+    /// Subterm the Law was applied to. Each Element of the Path selects a Child: 0 the left
+    /// Operand or the Operand of a Negation, 1 the right Operand.
+    pub fn subterm_before(&self) -> &ASTNode {
+        subtree(&self.before, &self.path)
+    }
+    /// Subterm the Law rewrote it to.
+    pub fn subterm_after(&self) -> &ASTNode {
+        subtree(&self.after, &self.path)
+    }
+    /// Path as Names of the Children, e.g. `lhs.operand.rhs`, or `root`.
+    pub fn path_names(&self) -> String {
+        if (self.path.is_empty()) {
+            return "root".to_string();
+        }
+        let mut node = &self.before;
+        let mut names = Vec::with_capacity(self.path.len());
+        for idx in self.path.iter() {
+            names.push(match (node, idx) {
+                (ASTNode::UnaryOperation { .. }, 0) => "operand",
+                (ASTNode::InfixOperation { .. }, 0) => "lhs",
+                (ASTNode::InfixOperation { .. }, 1) => "rhs",
+                _ => unreachable!(),
+            });
+            node = subtree(node, &[*idx]);
+        }
+        names.join(".")
+    }
+}
+
+/// Prints the Rewrite with Integer-Identifiers, `Parsed::render_derivation` prints the Names.
+impl Display for RewriteStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:b} = {:b}    {} at {}",
+            self.subterm_before(),
+            self.subterm_after(),
+            self.law,
+            self.path_names()
+        )
+    }
+}
+
+/// Numbered Derivation of the Expression from the first Step on, one Line per Step with the
+/// Law and the Subterm it was applied to. Without Steps only the Expression itself is printed.
+pub(crate) fn render_derivation<S: AsRef<str>>(
+    expression: &ASTNode,
+    steps: &[RewriteStep],
+    bindings: &[S],
+) -> String {
+    let printer = Printer::new();
+    let start = steps.first().map_or(expression, |step| &step.before);
+    let lines: Vec<String> = std::iter::once(start)
+        .chain(steps.iter().map(|step| &step.after))
+        .map(|expression| printer.print(expression, bindings))
+        .collect();
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let number_width = steps.len().to_string().len();
+
+    let mut out = String::new();
+    for (idx, line) in lines.iter().enumerate() {
+        let prefix = format!("{:>w$}. ", idx, w = number_width);
+        match idx.checked_sub(1).map(|step| &steps[step]) {
+            None => out.push_str(&format!("{}{}\n", prefix, line)),
+            Some(step) => out.push_str(&format!(
+                "{}{:<w$}    {} at {}\n",
+                prefix,
+                line,
+                step.law,
+                step.path_names(),
+                w = width
+            )),
+        }
+    }
+    out
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Simplifies the Expression bottom-up, applying the Laws to every Subtree until none of them
+/// applies anymore. Subtrees are compared structurally, and every Law keeps the Function the
+/// same.
+pub(crate) fn simplify(node: ASTNode) -> ASTNode {
+    simplify_at(node, &mut Vec::new(), &mut Vec::new())
+}
+
+/// Like `simplify`, but also returns every Rewrite in the Order it was applied.
+pub(crate) fn simplify_traced(node: ASTNode) -> (ASTNode, Vec<RewriteStep>) {
+    let mut rewrites = Vec::new();
+    let simplified = simplify_at(node.clone(), &mut Vec::new(), &mut rewrites);
+    // Replay the Rewrites of the Subtrees on the whole Expression
+    let mut expression = node;
+    let steps = rewrites
+        .into_iter()
+        .map(|(law, path, subterm)| {
+            let before = expression.clone();
+            *subtree_mut(&mut expression, &path) = subterm;
+            RewriteStep {
+                law,
+                path,
+                before,
+                after: expression.clone(),
+            }
+        })
+        .collect();
+    (simplified, steps)
+}
+
+/// Simplifies the Subtree at the Path, recording each Rewrite as its Law, Path and the
+/// rewritten Subtree.
+fn simplify_at(
+    node: ASTNode,
+    path: &mut Vec<usize>,
+    rewrites: &mut Vec<(Law, Vec<usize>, ASTNode)>,
+) -> ASTNode {
+    let mut child = |node: ASTNode, idx: usize| {
+        path.push(idx);
+        let simplified = simplify_at(node, path, rewrites);
+        path.pop();
+        Box::new(simplified)
+    };
+    let node = match node {
+        ASTNode::UnaryOperation { op, expr } => ASTNode::UnaryOperation {
+            op,
+            expr: child(*expr, 0),
+        },
+        ASTNode::InfixOperation { op, lhs, rhs } => ASTNode::InfixOperation {
+            op,
+            lhs: child(*lhs, 0),
+            rhs: child(*rhs, 1),
+        },
+        node => node,
+    };
+    match rewrite(&node) {
+        // The Result may contain new Subtrees that can be simplified further
+        Some((law, rewritten)) => {
+            rewrites.push((law, path.clone(), rewritten.clone()));
+            simplify_at(rewritten, path, rewrites)
+        }
+        None => node,
+    }
+}
+
+/// Applies the first Law that matches the Root of the Expression.
+pub(crate) fn rewrite(node: &ASTNode) -> Option<(Law, ASTNode)> {
+    RULES.iter().find_map(|(law, rule)| {
+        let rewritten = rule(node)?;
+        debug_assert!(size(&rewritten) < size(node), "{:?} grew {}", law, node);
+        Some((*law, rewritten))
+    })
+}
+
+/// Number of Nodes of the Expression.
+pub(crate) fn size(node: &ASTNode) -> usize {
+    match node {
+        ASTNode::UnaryOperation { expr, .. } => 1 + size(expr),
+        ASTNode::InfixOperation { lhs, rhs, .. } => 1 + size(lhs) + size(rhs),
+        _ => 1,
+    }
+}
+
+/// Subtree at the Path, where 0 selects the left Operand or the Operand of a Negation and 1
+/// the right Operand.
+pub(crate) fn subtree<'n>(node: &'n ASTNode, path: &[usize]) -> &'n ASTNode {
+    path.iter().fold(node, |node, idx| match (node, idx) {
+        (ASTNode::UnaryOperation { expr, .. }, 0)
+        | (ASTNode::InfixOperation { lhs: expr, .. }, 0) => expr,
+        (ASTNode::InfixOperation { rhs, .. }, 1) => rhs,
+        _ => unreachable!(),
+    })
+}
+
+fn subtree_mut<'n>(node: &'n mut ASTNode, path: &[usize]) -> &'n mut ASTNode {
+    path.iter().fold(node, |node, idx| match (node, idx) {
+        (ASTNode::UnaryOperation { expr, .. }, 0)
+        | (ASTNode::InfixOperation { lhs: expr, .. }, 0) => expr,
+        (ASTNode::InfixOperation { rhs, .. }, 1) => rhs,
+        _ => unreachable!(),
+    })
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+fn complement_of_literal(node: &ASTNode) -> Option<ASTNode> {
+    match node {
+        ASTNode::UnaryOperation {
+            op: UnaryOperator::Not,
+            expr,
+        } => match **expr {
+            ASTNode::Literal(l) => Some(ASTNode::Literal(!l)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn double_negation(node: &ASTNode) -> Option<ASTNode> {
+    match node {
+        ASTNode::UnaryOperation {
+            op: UnaryOperator::Not,
+            expr,
+        } => match &**expr {
+            ASTNode::UnaryOperation {
+                op: UnaryOperator::Not,
+                expr,
+            } => Some((**expr).clone()),
+            _ => None,
+        },
+        ASTNode::InfixOperation {
+            op: op @ (InfixOperator::Xor | InfixOperator::Equivalence),
+            lhs,
+            rhs,
+        } => match (&**lhs, &**rhs) {
+            (
+                ASTNode::UnaryOperation { expr: lhs, .. },
+                ASTNode::UnaryOperation { expr: rhs, .. },
+            ) => Some(ASTNode::infix(*op, (**lhs).clone(), (**rhs).clone())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Pushes a Negation into a Conjunction or Disjunction, if that cancels enough Negations of
+/// the Operands to make the Expression smaller.
+fn de_morgan_inwards(node: &ASTNode) -> Option<ASTNode> {
+    let ASTNode::UnaryOperation {
+        op: UnaryOperator::Not,
+        expr,
+    } = node
+    else {
+        return None;
+    };
+    let op = chain_operator(expr)?;
+    let negated = operands(op, expr).into_iter().cloned().map(ASTNode::negate);
+    let rewritten = chain(dual(op), negated);
+    (size(&rewritten) < size(node)).then_some(rewritten)
+}
+
+/// Pulls the Negation out of a Conjunction or Disjunction of negated Operands.
+fn de_morgan_outwards(node: &ASTNode) -> Option<ASTNode> {
+    let op = chain_operator(node)?;
+    let operands = operands(op, node);
+    let inner = operands
+        .iter()
+        .map(|operand| match operand {
+            ASTNode::UnaryOperation {
+                op: UnaryOperator::Not,
+                expr,
+            } => Some((**expr).clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<ASTNode>>>()?;
+    Some(chain(dual(op), inner).negate())
+}
+
+fn identity(node: &ASTNode) -> Option<ASTNode> {
+    if let Some(op) = chain_operator(node) {
+        let neutral = ASTNode::Literal(op == InfixOperator::And);
+        let operands = operands(op, node);
+        if !operands.contains(&&neutral) {
+            return None;
+        }
+        let rest = operands.into_iter().filter(|o| **o != neutral).cloned();
+        return Some(chain(op, rest));
+    }
+    let ASTNode::InfixOperation { op, lhs, rhs } = node else {
+        return None;
+    };
+    match (op, &**lhs, &**rhs) {
+        (InfixOperator::Xor, other, ASTNode::Literal(false))
+        | (InfixOperator::Xor, ASTNode::Literal(false), other)
+        | (InfixOperator::Equivalence, other, ASTNode::Literal(true))
+        | (InfixOperator::Equivalence, ASTNode::Literal(true), other)
+        | (InfixOperator::Implication, ASTNode::Literal(true), other) => Some(other.clone()),
+        _ => None,
+    }
+}
+
+fn domination(node: &ASTNode) -> Option<ASTNode> {
+    if let Some(op) = chain_operator(node) {
+        let dominant = ASTNode::Literal(op == InfixOperator::Or);
+        return operands(op, node).contains(&&dominant).then_some(dominant);
+    }
+    match node {
+        ASTNode::InfixOperation {
+            op: InfixOperator::Implication,
+            lhs,
+            rhs,
+        } if matches!(**lhs, ASTNode::Literal(false))
+            || matches!(**rhs, ASTNode::Literal(true)) =>
+        {
+            Some(ASTNode::Literal(true))
+        }
+        ASTNode::InfixOperation { lhs, rhs, .. }
+            if matches!((&**lhs, &**rhs), (ASTNode::Literal(_), ASTNode::Literal(_))) =>
+        {
+            Some(ASTNode::Literal(node.evaluate_slice(&[])))
+        }
+        _ => None,
+    }
+}
+
+/// Constants that negate the other Operand: x ^ true = !x, x <-> false = !x, x -> false = !x.
+fn complement_of_constant_operand(node: &ASTNode) -> Option<ASTNode> {
+    let ASTNode::InfixOperation { op, lhs, rhs } = node else {
+        return None;
+    };
+    match (op, &**lhs, &**rhs) {
+        (InfixOperator::Xor, other, ASTNode::Literal(true))
+        | (InfixOperator::Xor, ASTNode::Literal(true), other)
+        | (InfixOperator::Equivalence, other, ASTNode::Literal(false))
+        | (InfixOperator::Equivalence, ASTNode::Literal(false), other)
+        | (InfixOperator::Implication, other, ASTNode::Literal(false)) => {
+            Some(other.clone().negate())
+        }
+        _ => None,
+    }
+}
+
+fn idempotence(node: &ASTNode) -> Option<ASTNode> {
+    let op = chain_operator(node)?;
+    let operands = operands(op, node);
+    let mut unique: Vec<ASTNode> = Vec::with_capacity(operands.len());
+    for operand in operands.iter() {
+        if !unique.contains(operand) {
+            unique.push((*operand).clone());
+        }
+    }
+    (unique.len() < operands.len()).then(|| chain(op, unique))
+}
+
+fn reflexivity(node: &ASTNode) -> Option<ASTNode> {
+    match node {
+        ASTNode::InfixOperation { op, lhs, rhs } if lhs == rhs => match op {
+            InfixOperator::Xor => Some(ASTNode::Literal(false)),
+            InfixOperator::Implication | InfixOperator::Equivalence => Some(ASTNode::Literal(true)),
+            // Handled by Idempotence
+            InfixOperator::And | InfixOperator::Or => None,
+        },
+        _ => None,
+    }
+}
+
+/// An Operand together with its Negation: x & !x = false, x ^ !x = true, x -> !x = !x, ...
+fn complement(node: &ASTNode) -> Option<ASTNode> {
+    if let Some(op) = chain_operator(node) {
+        let operands = operands(op, node);
+        let contradicts = operands
+            .iter()
+            .any(|o| operands.contains(&&(*o).clone().negate()));
+        return contradicts.then_some(ASTNode::Literal(op == InfixOperator::Or));
+    }
+    let ASTNode::InfixOperation { op, lhs, rhs } = node else {
+        return None;
+    };
+    if (**lhs != (**rhs).clone().negate()) {
+        return None;
+    }
+    match op {
+        InfixOperator::Xor => Some(ASTNode::Literal(true)),
+        InfixOperator::Equivalence => Some(ASTNode::Literal(false)),
+        InfixOperator::Implication => Some((**rhs).clone()),
+        InfixOperator::And | InfixOperator::Or => unreachable!(),
+    }
+}
+
+/// Drops Operands that contain every Factor of another Operand, e.g. the Conjunction
+/// `x & y` in `x | (x & y)`.
+fn absorption(node: &ASTNode) -> Option<ASTNode> {
+    let op = chain_operator(node)?;
+    let operands = operands(op, node);
+    let factors: Vec<Vec<&ASTNode>> = operands
+        .iter()
+        .map(|o| self::operands(dual(op), o))
+        .collect();
+    let absorbed = |idx: usize| {
+        (0..operands.len()).any(|other| {
+            other != idx
+                && operands[other] != operands[idx]
+                && factors[other].iter().all(|f| factors[idx].contains(f))
+                // Of two Operands with the same Factors in a different Order, keep the first
+                && (other < idx || !factors[idx].iter().all(|f| factors[other].contains(f)))
+        })
+    };
+    let kept: Vec<ASTNode> = (0..operands.len())
+        .filter(|idx| !absorbed(*idx))
+        .map(|idx| operands[idx].clone())
+        .collect();
+    (kept.len() < operands.len()).then(|| chain(op, kept))
+}
+
+/// Drops an Operand whose Factors contain the Consensus of two other Operands, i.e. every
+/// Factor of both of them except for a Variable that occurs in one and is negated in the
+/// other.
+fn consensus(node: &ASTNode) -> Option<ASTNode> {
+    let op = chain_operator(node)?;
+    let operands = operands(op, node);
+    let factors: Vec<Vec<&ASTNode>> = operands
+        .iter()
+        .map(|o| self::operands(dual(op), o))
+        .collect();
+    for (p, q) in (0..operands.len()).flat_map(|p| (0..operands.len()).map(move |q| (p, q))) {
+        for x in factors[p].iter() {
+            let negated = (*x).clone().negate();
+            if (p == q || !factors[q].contains(&&negated)) {
+                continue;
+            }
+            let consensus: Vec<&ASTNode> = factors[p]
+                .iter()
+                .filter(|f| **f != *x)
+                .chain(factors[q].iter().filter(|f| ***f != negated))
+                .copied()
+                .collect();
+            let redundant = (0..operands.len())
+                .find(|r| *r != p && *r != q && consensus.iter().all(|f| factors[*r].contains(f)));
+            if let Some(r) = redundant {
+                let kept = (0..operands.len()).filter(|idx| *idx != r);
+                return Some(chain(op, kept.map(|idx| operands[idx].clone())));
+            }
+        }
+    }
+    None
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// The Operator of a Conjunction or Disjunction.
+fn chain_operator(node: &ASTNode) -> Option<InfixOperator> {
+    match node {
+        ASTNode::InfixOperation {
+            op: op @ (InfixOperator::And | InfixOperator::Or),
+            ..
+        } => Some(*op),
+        _ => None,
+    }
+}
+
+fn dual(op: InfixOperator) -> InfixOperator {
+    match op {
+        InfixOperator::And => InfixOperator::Or,
+        InfixOperator::Or => InfixOperator::And,
+        _ => unreachable!(),
+    }
+}
+
+/// Operands of a Chain of the associative Operator, in Order. A Node that isn't such a Chain
+/// is its only Operand.
+pub(crate) fn operands(op: InfixOperator, node: &ASTNode) -> Vec<&ASTNode> {
+    match node {
+        ASTNode::InfixOperation { op: o, lhs, rhs } if *o == op => {
+            let mut operands = self::operands(op, lhs);
+            operands.extend(self::operands(op, rhs));
+            operands
+        }
+        _ => vec![node],
+    }
+}
+
+/// Left-associative Chain of the Operands, the neutral Element if there are none.
+pub(crate) fn chain(op: InfixOperator, operands: impl IntoIterator<Item = ASTNode>) -> ASTNode {
+    operands
+        .into_iter()
+        .reduce(|lhs, rhs| ASTNode::infix(op, lhs, rhs))
+        .unwrap_or(ASTNode::Literal(op == InfixOperator::And))
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -0,0 +1,254 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::{ast_node::ASTNode, binding::Bindings, dialect::Notation, printer::Printer};
+
+use std::fmt::{Display, Formatter};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Output-Formats of `TruthTable::render`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum TableFormat {
+    /// Aligned plain-text Columns.
+    #[default]
+    Ascii,
+    Markdown,
+    Csv,
+    /// A LaTeX `tabular`-Environment. Names are set with `\text{}`, which needs `amsmath`.
+    Latex,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Truth-Table of an Expression, see `Parsed::truth_table`.
+///
+/// Rows are ordered by counting in Binary with the first Variable as the most significant Bit,
+/// so the Index of a Row is the Index of the corresponding Minterm.
+#[derive(Debug, Clone)]
+pub struct TruthTable {
+    variables: Vec<String>,
+    expression: ASTNode,
+    outputs: Vec<bool>,
+}
+
+/// A single Row of a `TruthTable`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub index: usize,
+    pub inputs: Vec<bool>,
+    pub output: bool,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl TruthTable {
+    /// Evaluates the Expression for every Assignment of the Variables.
+    ///
+    /// # Panics
+    /// If there are too many Variables to enumerate every Assignment.
+    pub(crate) fn new(expression: &ASTNode, variables: Vec<String>) -> Self {
+        let n = variables.len();
+        assert!(
+            n < usize::BITS as usize,
+            "too many variables for a truth table"
+        );
+        let names: Vec<&str> = variables.iter().map(String::as_str).collect();
+        let outputs = Bindings::from(names)
+            .assignments()
+            .map(|values| expression.evaluate_slice(&values))
+            .collect();
+        Self {
+            variables,
+            expression: expression.clone(),
+            outputs,
+        }
+    }
+    /// Names of the Input-Columns, in the Order of the Variable-Identifiers.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+    /// The Output-Column, indexed by Row.
+    pub fn outputs(&self) -> &[bool] {
+        &self.outputs
+    }
+    pub fn len(&self) -> usize {
+        self.outputs.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.outputs.is_empty()
+    }
+    pub fn row(&self, index: usize) -> Row {
+        Row {
+            index,
+            inputs: row_inputs(index, self.variables.len()),
+            output: self.outputs[index],
+        }
+    }
+    pub fn rows(&self) -> impl Iterator<Item = Row> + '_ {
+        (0..self.len()).map(move |index| self.row(index))
+    }
+    /// Rows in which the Expression is true.
+    pub fn true_rows(&self) -> impl Iterator<Item = Row> + '_ {
+        self.rows().filter(|row| row.output)
+    }
+    /// Rows in which the Expression is false.
+    pub fn false_rows(&self) -> impl Iterator<Item = Row> + '_ {
+        self.rows().filter(|row| !row.output)
+    }
+    pub fn render(&self, format: TableFormat) -> String {
+        match format {
+            TableFormat::Ascii => self.render_ascii(),
+            TableFormat::Markdown => self.render_markdown(),
+            TableFormat::Csv => self.render_csv(),
+            TableFormat::Latex => self.render_latex(),
+        }
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl TruthTable {
+    fn formula(&self, notation: Notation) -> String {
+        Printer::new()
+            .with_notation(notation)
+            .print(&self.expression, &self.variables)
+    }
+    /// Header-Cells and their Widths, the last one is the Output-Column.
+    fn header(&self, formula: String) -> Vec<(String, usize)> {
+        self.variables
+            .iter()
+            .cloned()
+            .chain(std::iter::once(formula))
+            .map(|cell| {
+                let width = cell.chars().count().max(1);
+                (cell, width)
+            })
+            .collect()
+    }
+    fn render_ascii(&self) -> String {
+        let header = self.header(self.formula(Notation::Ascii));
+        let (output, inputs) = header.split_last().unwrap();
+        let line = |cells: Vec<String>| {
+            let (last, first) = cells.split_last().unwrap();
+            let inputs: Vec<String> = first
+                .iter()
+                .zip(inputs)
+                .map(|(cell, (_, width))| format!("{:<w$}", cell, w = width))
+                .collect();
+            format!("{} | {}\n", inputs.join(" "), last)
+        };
+
+        let mut out = line(header.iter().map(|(cell, _)| cell.clone()).collect());
+        let inputs_width = inputs.iter().map(|(_, w)| w + 1).sum::<usize>();
+        out.push_str(&"-".repeat(inputs_width.max(1)));
+        out.push('+');
+        out.push_str(&"-".repeat(output.1 + 1));
+        out.push('\n');
+        for row in self.rows() {
+            out.push_str(&line(row_cells(&row)));
+        }
+        out
+    }
+    fn render_markdown(&self) -> String {
+        let header: Vec<(String, usize)> = self
+            .header(self.formula(Notation::Ascii))
+            .into_iter()
+            .map(|(cell, width)| {
+                let escaped = cell.replace('|', "\\|");
+                let width = width + escaped.len() - cell.len();
+                (escaped, width)
+            })
+            .collect();
+        let line = |cells: Vec<String>| {
+            let cells: Vec<String> = cells
+                .iter()
+                .zip(&header)
+                .map(|(cell, (_, width))| format!("{:<w$}", cell, w = width))
+                .collect();
+            format!("| {} |\n", cells.join(" | "))
+        };
+
+        let mut out = line(header.iter().map(|(cell, _)| cell.clone()).collect());
+        out.push_str(&line(header.iter().map(|(_, w)| "-".repeat(*w)).collect()));
+        for row in self.rows() {
+            out.push_str(&line(row_cells(&row)));
+        }
+        out
+    }
+    fn render_csv(&self) -> String {
+        let quote = |cell: &str| {
+            if cell.contains([',', '"', '\n']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.to_string()
+            }
+        };
+        let header = self.header(self.formula(Notation::Ascii));
+        let mut out = header
+            .iter()
+            .map(|(cell, _)| quote(cell))
+            .collect::<Vec<String>>()
+            .join(",");
+        out.push('\n');
+        for row in self.rows() {
+            out.push_str(&row_cells(&row).join(","));
+            out.push('\n');
+        }
+        out
+    }
+    fn render_latex(&self) -> String {
+        let printer = Printer::new()
+            .with_notation(Notation::Latex)
+            .with_latex_names();
+        let cell = |node: &ASTNode| format!("${}$", printer.print(node, &self.variables));
+        let mut header: Vec<String> = (0..self.variables.len())
+            .map(|i| cell(&ASTNode::Identifier(i)))
+            .collect();
+        header.push(cell(&self.expression));
+
+        let mut out = format!(
+            "\\begin{{tabular}}{{{}|c}}\n",
+            "c".repeat(self.variables.len())
+        );
+        out.push_str(&format!("{} \\\\\n\\hline\n", header.join(" & ")));
+        for row in self.rows() {
+            out.push_str(&format!("{} \\\\\n", row_cells(&row).join(" & ")));
+        }
+        out.push_str("\\end{tabular}\n");
+        out
+    }
+}
+
+/// Prints the Table as aligned plain Text.
+impl Display for TruthTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render_ascii())
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Assignment of the Row with the given Index, the first Variable is the most significant Bit.
+fn row_inputs(index: usize, num_variables: usize) -> Vec<bool> {
+    (0..num_variables)
+        .map(|i| {
+            index
+                .checked_shr((num_variables - 1 - i) as u32)
+                .unwrap_or(0)
+                & 1
+                == 1
+        })
+        .collect()
+}
+
+fn row_cells(row: &Row) -> Vec<String> {
+    row.inputs
+        .iter()
+        .chain(std::iter::once(&row.output))
+        .map(|v| if *v { "1" } else { "0" }.to_string())
+        .collect()
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -0,0 +1,208 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::{
+    ast_node::ASTNode,
+    binding::Bindings,
+    normal_form::{Clause, Cnf, Literal},
+    operators::{InfixOperator, UnaryOperator},
+    parsing::Parsed,
+};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Which Clauses `Parsed::tseitin` adds for every Subformula.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum Encoding {
+    /// Both Directions of the Definition `t <-> subformula`, so the Clauses determine every
+    /// auxiliary Variable from the original ones.
+    Tseitin,
+    /// Only the Direction the Subformula's Polarity needs, about half the Clauses. Models
+    /// still restrict to Models of the Expression, but auxiliary Variables may be true where
+    /// their Subformula is false (or the other way around).
+    #[default]
+    PlaistedGreenbaum,
+}
+
+/// Equisatisfiable CNF of an Expression with one auxiliary Variable per Subformula, see
+/// `Parsed::tseitin`.
+///
+/// The Variables are those of the Expression followed by the auxiliary ones, which are named
+/// with the reserved Prefix `_t` and a Number.
+#[derive(Debug, Clone)]
+pub struct TseitinCnf<'a> {
+    parsed: Parsed<'a>,
+    cnf: Cnf,
+    num_original: usize,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl<'a> TseitinCnf<'a> {
+    /// Prefix of the Names of the auxiliary Variables.
+    pub const PREFIX: &'static str = "_t";
+
+    /// Encodes the Expression, naming the auxiliary Variables after the ones already taken.
+    pub(crate) fn new(parsed: &Parsed<'a>, encoding: Encoding) -> Self {
+        let num_original = parsed.num_variables();
+        let mut encoder = Encoder {
+            encoding,
+            clauses: Vec::new(),
+            num_variables: num_original,
+            constant: None,
+        };
+        let root = encoder.encode(parsed.expression(), Polarity::Positive);
+        encoder.clauses.push(Clause {
+            literals: vec![root],
+        });
+
+        let mut names: Vec<String> = Vec::new();
+        let mut number = 0;
+        while num_original + names.len() < encoder.num_variables {
+            number += 1;
+            let name = format!("{}{}", Self::PREFIX, number);
+            if !parsed.variable_names().any(|n| n == name) {
+                names.push(name);
+            }
+        }
+        Self {
+            parsed: parsed.with_variables(names),
+            cnf: Cnf {
+                clauses: encoder.clauses,
+            },
+            num_original,
+        }
+    }
+    /// The Expression with the auxiliary Variables added to its Variables.
+    pub fn parsed(&self) -> &Parsed<'a> {
+        &self.parsed
+    }
+    pub fn cnf(&self) -> &Cnf {
+        &self.cnf
+    }
+    pub fn clauses(&self) -> &[Clause] {
+        &self.cnf.clauses
+    }
+    /// Number of Variables including the auxiliary ones.
+    pub fn num_variables(&self) -> usize {
+        self.parsed.num_variables()
+    }
+    pub fn num_original_variables(&self) -> usize {
+        self.num_original
+    }
+    /// Assignment of the original Variables in a Model of the Clauses, given as the Value of
+    /// every Variable including the auxiliary ones. The Expression is true for it.
+    pub fn original_assignment(&self, model: &[bool]) -> Bindings<'_> {
+        let assignment: Vec<(&str, bool)> = self
+            .parsed
+            .variable_names()
+            .zip(model.iter().copied())
+            .take(self.num_original)
+            .collect();
+        Bindings::from(assignment)
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Whether a Subformula occurs unnegated, negated or both (below Xor and Equivalence).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Polarity {
+    Positive,
+    Negative,
+    Both,
+}
+
+impl Polarity {
+    fn flip(self) -> Self {
+        match self {
+            Polarity::Positive => Polarity::Negative,
+            Polarity::Negative => Polarity::Positive,
+            Polarity::Both => Polarity::Both,
+        }
+    }
+}
+
+struct Encoder {
+    encoding: Encoding,
+    clauses: Vec<Clause>,
+    num_variables: usize,
+    /// Auxiliary Variable that is forced true, shared by all Constants.
+    constant: Option<usize>,
+}
+
+impl Encoder {
+    /// Literal that stands for the Subformula, adding the Clauses that define it. Variables
+    /// and their Negations stand for themselves.
+    fn encode(&mut self, node: &ASTNode, polarity: Polarity) -> Literal {
+        match node {
+            ASTNode::Identifier(i) => Literal::new(*i, false),
+            ASTNode::Literal(value) => {
+                let constant = match self.constant {
+                    Some(constant) => constant,
+                    None => {
+                        let constant = self.fresh();
+                        self.clauses.push(Clause {
+                            literals: vec![Literal::new(constant, false)],
+                        });
+                        self.constant = Some(constant);
+                        constant
+                    }
+                };
+                Literal::new(constant, !value)
+            }
+            ASTNode::UnaryOperation {
+                op: UnaryOperator::Not,
+                expr,
+            } => !self.encode(expr, polarity.flip()),
+            ASTNode::InfixOperation { op, lhs, rhs } => {
+                let (lhs_polarity, rhs_polarity) = match op {
+                    InfixOperator::And | InfixOperator::Or => (polarity, polarity),
+                    InfixOperator::Implication => (polarity.flip(), polarity),
+                    InfixOperator::Xor | InfixOperator::Equivalence => {
+                        (Polarity::Both, Polarity::Both)
+                    }
+                };
+                let a = self.encode(lhs, lhs_polarity);
+                let b = self.encode(rhs, rhs_polarity);
+                let t = Literal::new(self.fresh(), false);
+                // Clauses of t -> op(a, b), and of op(a, b) -> t
+                let (implies, implied) = match op {
+                    InfixOperator::And => (vec![vec![!t, a], vec![!t, b]], vec![vec![t, !a, !b]]),
+                    InfixOperator::Or => (vec![vec![!t, a, b]], vec![vec![t, !a], vec![t, !b]]),
+                    InfixOperator::Implication => {
+                        (vec![vec![!t, !a, b]], vec![vec![t, a], vec![t, !b]])
+                    }
+                    InfixOperator::Xor => (
+                        vec![vec![!t, a, b], vec![!t, !a, !b]],
+                        vec![vec![t, !a, b], vec![t, a, !b]],
+                    ),
+                    InfixOperator::Equivalence => (
+                        vec![vec![!t, !a, b], vec![!t, a, !b]],
+                        vec![vec![t, a, b], vec![t, !a, !b]],
+                    ),
+                };
+                let both = self.encoding == Encoding::Tseitin || polarity == Polarity::Both;
+                if (both || polarity == Polarity::Positive) {
+                    self.add(implies);
+                }
+                if (both || polarity == Polarity::Negative) {
+                    self.add(implied);
+                }
+                t
+            }
+            _ => unreachable!(),
+        }
+    }
+    fn fresh(&mut self) -> usize {
+        self.num_variables += 1;
+        self.num_variables - 1
+    }
+    fn add(&mut self, clauses: Vec<Vec<Literal>>) {
+        self.clauses
+            .extend(clauses.into_iter().map(|literals| Clause { literals }));
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -0,0 +1,314 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::{
+    ast_node::ASTNode,
+    operators::{InfixOperator, UnaryOperator},
+};
+use crate::solver::minterm::{sort_terms, Minterm, TruthValue};
+
+use std::collections::HashSet;
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Heuristic two-level Minimization of the Function given by Cube-Lists for its On-, Don't-Care
+/// and Off-Set, in the Style of Espresso.
+///
+/// The Result is a Cover of the On-Set that doesn't intersect the Off-Set, but isn't
+/// necessarily minimal. Rows that are in neither the On- nor the Off-Set are Don't-Cares
+/// already, listing them in `dont_cares` lets the Result drop Cubes covered by them. Nothing
+/// here enumerates the Rows of the Truth-Table, so it also works for Functions with many
+/// Variables where `quine_mccluskey::minimize` is hopeless. The On-Cover is expanded into
+/// prime Cubes and made irredundant, then the REDUCE / EXPAND / IRREDUNDANT Loop runs for as
+/// long as it makes the Cover cheaper.
+pub fn minimize(on_set: &[Minterm], dont_cares: &[Minterm], off_set: &[Minterm]) -> Vec<Minterm> {
+    let off_set = remove_contained(off_set.to_vec());
+    let dont_cares = remove_contained(dont_cares.to_vec());
+    let mut cover = irredundant(
+        expand(remove_contained(on_set.to_vec()), &off_set),
+        &dont_cares,
+    );
+    loop {
+        let next = irredundant(
+            expand(reduce(cover.clone(), &dont_cares), &off_set),
+            &dont_cares,
+        );
+        if (cost(&next) >= cost(&cover)) {
+            break;
+        }
+        cover = next;
+    }
+    sort_terms(&mut cover);
+    cover
+}
+
+/// Cube-Lists for the On- and Off-Set of the Expression.
+///
+/// One Side of every Operation is the Union of the Operands' Cube-Lists, e.g. the Off-Set of a
+/// Conjunction, the other Side is its `complement`. Nothing is multiplied out, so a Product of
+/// many Clauses only needs one Complementation of the Union of their Off-Cubes. The Cube-Lists
+/// are still as large as the Function requires, e.g. the On-Set of a random CNF over many
+/// Variables can need Thousands of Cubes, and so can the Off-Set of a random DNF.
+pub fn on_off_sets(expression: &ASTNode, num_variables: usize) -> (Vec<Minterm>, Vec<Minterm>) {
+    (
+        cover(expression, true, num_variables),
+        cover(expression, false, num_variables),
+    )
+}
+
+/// Cubes covering exactly the Rows that none of the given Cubes covers.
+///
+/// Unate recursive Complementation: the Cubes are split on the Variable that occurs in most of
+/// them, preferring Variables that occur in both Polarities, and the Complements of both
+/// Cofactors are joined again. Cubes that occur in both Halves don't need the split Variable,
+/// and if both Cofactors are the same, the Variable isn't split on at all.
+pub fn complement(cubes: &[Minterm], num_variables: usize) -> Vec<Minterm> {
+    if cubes.iter().any(|c| c.num_literals() == 0) {
+        return Vec::new();
+    }
+    let literal = |i: usize, value: TruthValue| {
+        let mut v = vec![TruthValue::DontCare; num_variables];
+        v[i] = value;
+        Minterm::from(v)
+    };
+    match cubes {
+        [] => return vec![Minterm::universe(num_variables)],
+        // De Morgan: one Cube for every negated Literal
+        [cube] => {
+            return (0..num_variables)
+                .filter(|i| cube[*i] != TruthValue::DontCare)
+                .map(|i| literal(i, !cube[i]))
+                .collect();
+        }
+        _ => {}
+    }
+    let split = (0..num_variables)
+        .max_by_key(|i| {
+            let count = |value: TruthValue| cubes.iter().filter(|c| c[*i] == value).count();
+            let (positive, negative) = (count(TruthValue::True), count(TruthValue::False));
+            (positive > 0 && negative > 0, positive + negative)
+        })
+        .unwrap();
+    let [positive, negative] = [TruthValue::True, TruthValue::False].map(|value| {
+        let half = literal(split, value);
+        let cofactors = cubes.iter().filter_map(|c| c.cofactor(&half)).collect();
+        (half, remove_contained(cofactors))
+    });
+    // The Variable doesn't matter, e.g. for `a & b | a & !b`
+    let set = |cubes: &[Minterm]| cubes.iter().cloned().collect::<HashSet<Minterm>>();
+    if (set(&positive.1) == set(&negative.1)) {
+        return complement(&positive.1, num_variables);
+    }
+    let [positive, negative] =
+        [positive, negative].map(|(half, cofactors)| (half, complement(&cofactors, num_variables)));
+
+    let shared: HashSet<&Minterm> = positive.1.iter().collect();
+    let mut result: Vec<Minterm> = negative
+        .1
+        .iter()
+        .filter(|c| shared.contains(c))
+        .cloned()
+        .collect();
+    let both: HashSet<Minterm> = result.iter().cloned().collect();
+    for (half, cubes) in [positive, negative] {
+        result.extend(
+            cubes
+                .into_iter()
+                .filter(|c| !both.contains(c))
+                .filter_map(|c| c.intersect(&half)),
+        );
+    }
+    remove_contained(result)
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Number of Cubes, then Number of Literals.
+fn cost(cover: &[Minterm]) -> (usize, usize) {
+    (cover.len(), cover.iter().map(Minterm::num_literals).sum())
+}
+
+/// Replaces every Cube by a prime one containing it, by dropping Literals for as long as the
+/// Cube doesn't intersect the Off-Set. Cubes contained in an expanded Cube are removed.
+fn expand(mut cover: Vec<Minterm>, off_set: &[Minterm]) -> Vec<Minterm> {
+    // Large Cubes first, they are the most likely to swallow other ones
+    cover.sort_by_key(Minterm::num_literals);
+    let mut expanded: Vec<Minterm> = Vec::new();
+    for cube in cover.iter() {
+        if expanded.iter().any(|e| e.contains(cube)) {
+            continue;
+        }
+        let mut values = cube.values().to_vec();
+        // Drop the Literals first that other Cubes disagree with, so the expanded Cube grows
+        // towards them.
+        let mut positions: Vec<usize> = (0..values.len())
+            .filter(|i| values[*i] != TruthValue::DontCare)
+            .collect();
+        positions.sort_by_key(|i| {
+            let opposite = !values[*i];
+            std::cmp::Reverse(cover.iter().filter(|c| c[*i] == opposite).count())
+        });
+        for i in positions {
+            let previous = values[i];
+            values[i] = TruthValue::DontCare;
+            let raised = Minterm::from(values.clone());
+            if off_set.iter().any(|off| off.intersects(&raised)) {
+                values[i] = previous;
+            }
+        }
+        let prime = Minterm::from(values);
+        expanded.retain(|e| !prime.contains(e));
+        expanded.push(prime);
+    }
+    expanded
+}
+
+/// Removes Cubes that are covered by the other ones and the Don't-Cares, trying the smallest
+/// Cubes first.
+fn irredundant(mut cover: Vec<Minterm>, dont_cares: &[Minterm]) -> Vec<Minterm> {
+    cover.sort_by_key(|c| std::cmp::Reverse(c.num_literals()));
+    let mut idx = 0;
+    while idx < cover.len() {
+        let cube = cover.remove(idx);
+        if (!is_covered(&cube, &[cover.as_slice(), dont_cares].concat())) {
+            cover.insert(idx, cube);
+            idx += 1;
+        }
+    }
+    cover
+}
+
+/// Shrinks every Cube to the Part that isn't covered by the other Cubes and the Don't-Cares,
+/// which lets the next EXPAND grow it in a different Direction.
+fn reduce(mut cover: Vec<Minterm>, dont_cares: &[Minterm]) -> Vec<Minterm> {
+    cover.sort_by_key(Minterm::num_literals);
+    for idx in 0..cover.len() {
+        let mut values = cover[idx].values().to_vec();
+        let others: Vec<Minterm> = cover
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != idx)
+            .map(|(_, c)| c.clone())
+            .chain(dont_cares.iter().cloned())
+            .collect();
+        for i in 0..values.len() {
+            if (values[i] != TruthValue::DontCare) {
+                continue;
+            }
+            for (dropped, kept) in [
+                (TruthValue::False, TruthValue::True),
+                (TruthValue::True, TruthValue::False),
+            ] {
+                values[i] = dropped;
+                let half = Minterm::from(values.clone());
+                if is_covered(&half, &others) {
+                    values[i] = kept;
+                    break;
+                }
+                values[i] = TruthValue::DontCare;
+            }
+        }
+        cover[idx] = Minterm::from(values);
+    }
+    cover
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Checks if every Row of the Cube is covered by the Cover, i.e. if the Cofactor of the Cover
+/// with respect to the Cube is a Tautology.
+fn is_covered(cube: &Minterm, cover: &[Minterm]) -> bool {
+    let cofactors: Vec<Minterm> = cover.iter().filter_map(|c| c.cofactor(cube)).collect();
+    is_tautology(&cofactors)
+}
+
+/// Checks if the Cubes cover every Row, by splitting on binate Variables.
+fn is_tautology(cubes: &[Minterm]) -> bool {
+    if cubes.iter().any(|c| c.num_literals() == 0) {
+        return true;
+    }
+    let Some(first) = cubes.first() else {
+        return false;
+    };
+    // A Cover in which every Variable only occurs in one Polarity is never a Tautology
+    // without a universal Cube: the Row with every Literal false isn't covered.
+    let binate = (0..first.len())
+        .filter(|i| {
+            cubes.iter().any(|c| c[*i] == TruthValue::True)
+                && cubes.iter().any(|c| c[*i] == TruthValue::False)
+        })
+        .max_by_key(|i| {
+            cubes
+                .iter()
+                .filter(|c| c[*i] != TruthValue::DontCare)
+                .count()
+        });
+    let Some(split) = binate else {
+        return false;
+    };
+    [TruthValue::True, TruthValue::False]
+        .into_iter()
+        .all(|value| {
+            let mut v = vec![TruthValue::DontCare; first.len()];
+            v[split] = value;
+            let half = Minterm::from(v);
+            let cofactors: Vec<Minterm> = cubes.iter().filter_map(|c| c.cofactor(&half)).collect();
+            is_tautology(&cofactors)
+        })
+}
+
+/// Cubes covering the Rows in which the Expression has the given Value, see `on_off_sets`.
+pub fn cover(expression: &ASTNode, value: bool, num_variables: usize) -> Vec<Minterm> {
+    let union = |operands: [(&ASTNode, bool); 2]| {
+        let cubes = operands
+            .iter()
+            .flat_map(|(operand, value)| cover(operand, *value, num_variables))
+            .collect();
+        remove_contained(cubes)
+    };
+    match expression {
+        ASTNode::Literal(l) if *l == value => vec![Minterm::universe(num_variables)],
+        ASTNode::Literal(_) => Vec::new(),
+        ASTNode::Identifier(i) => {
+            let mut v = vec![TruthValue::DontCare; num_variables];
+            v[*i] = TruthValue::from(value);
+            vec![Minterm::from(v)]
+        }
+        ASTNode::UnaryOperation {
+            op: UnaryOperator::Not,
+            expr,
+        } => cover(expr, !value, num_variables),
+        ASTNode::InfixOperation { op, lhs, rhs } => match (op, value) {
+            (InfixOperator::And, false) => union([(lhs, false), (rhs, false)]),
+            (InfixOperator::Or, true) => union([(lhs, true), (rhs, true)]),
+            (InfixOperator::Implication, true) => union([(lhs, false), (rhs, true)]),
+            (InfixOperator::And | InfixOperator::Or | InfixOperator::Implication, _) => {
+                complement(&cover(expression, !value, num_variables), num_variables)
+            }
+            // Both Operands have the opposite of the Values whose Union is complemented
+            (InfixOperator::Xor | InfixOperator::Equivalence, _) => {
+                let differ = (*op == InfixOperator::Xor) == value;
+                let sides = [(false, differ), (true, !differ)].map(|(lhs_value, rhs_value)| {
+                    complement(&union([(lhs, lhs_value), (rhs, rhs_value)]), num_variables)
+                });
+                remove_contained(sides.concat())
+            }
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Removes duplicate Cubes and Cubes that are contained in another one.
+fn remove_contained(mut cubes: Vec<Minterm>) -> Vec<Minterm> {
+    cubes.sort_by_key(Minterm::num_literals);
+    let mut result: Vec<Minterm> = Vec::with_capacity(cubes.len());
+    for cube in cubes {
+        if !result.iter().any(|r| r.contains(&cube)) {
+            result.push(cube);
+        }
+    }
+    result
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -1,9 +1,11 @@
 #![allow(dead_code, unused)]
 
+use crate::parser::{ast_node::ASTNode, operators::InfixOperator};
+
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Index, IndexMut, Not, Shr, ShrAssign};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum TruthValue {
     DontCare,
     True,
@@ -26,7 +28,7 @@ impl From<bool> for TruthValue {
 }
 
 impl Display for TruthValue {
-    fn fmt(&self, f: Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             TruthValue::DontCare => write!(f, "X"),
             TruthValue::False => write!(f, "F"),
@@ -101,10 +103,187 @@ impl ShrAssign for TruthValue {
     }
 }
 
+/// Product-Term over a fixed Number of Variables. Every Position is either a positive
+/// (`True`) or negated (`False`) Literal of that Variable, or doesn't appear (`DontCare`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Minterm {
     v: Vec<TruthValue>,
 }
 
+impl Minterm {
+    /// The Minterm of a single Row of the Truth-Table, the first Variable is the most
+    /// significant Bit of the Index. Variables beyond the Bits of a `usize` are false.
+    pub fn from_index(index: usize, num_variables: usize) -> Self {
+        Self {
+            v: (0..num_variables)
+                .map(|i| {
+                    let bit = index.checked_shr((num_variables - 1 - i) as u32);
+                    TruthValue::from(bit.unwrap_or(0) & 1 == 1)
+                })
+                .collect(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.v.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+    pub fn values(&self) -> &[TruthValue] {
+        &self.v
+    }
+    /// Number of Literals, i.e. of Positions that aren't `DontCare`.
+    pub fn num_literals(&self) -> usize {
+        self.v
+            .iter()
+            .filter(|t| !matches!(t, TruthValue::DontCare))
+            .count()
+    }
+    /// Number of Positions that are `True`.
+    pub fn num_true(&self) -> usize {
+        self.v
+            .iter()
+            .filter(|t| matches!(t, TruthValue::True))
+            .count()
+    }
+    /// Merges two Terms that differ in exactly one Position, where one has a positive and the
+    /// other a negated Literal. That Position becomes `DontCare` in the merged Term.
+    pub fn merge(&self, other: &Minterm) -> Option<Minterm> {
+        let mut difference = None;
+        for (idx, (t1, t2)) in self.v.iter().zip(other.v.iter()).enumerate() {
+            if (t1 != t2) {
+                if (difference.is_some()
+                    || matches!(t1, TruthValue::DontCare)
+                    || matches!(t2, TruthValue::DontCare))
+                {
+                    return None;
+                }
+                difference = Some(idx);
+            }
+        }
+        let idx = difference?;
+        let mut merged = self.clone();
+        merged.v[idx] = TruthValue::DontCare;
+        Some(merged)
+    }
+    /// Checks if the Term is true for the Row of the Truth-Table with the given Index.
+    pub fn covers(&self, index: usize) -> bool {
+        let n = self.v.len();
+        self.v.iter().enumerate().all(|(i, t)| {
+            let bit = index.checked_shr((n - 1 - i) as u32).unwrap_or(0) & 1 == 1;
+            match t {
+                TruthValue::DontCare => true,
+                TruthValue::True => bit,
+                TruthValue::False => !bit,
+            }
+        })
+    }
+    /// Checks if every Row covered by `other` is covered by this Term as well.
+    pub fn contains(&self, other: &Minterm) -> bool {
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .all(|(t1, t2)| matches!(t1, TruthValue::DontCare) || t1 == t2)
+    }
+    /// The Term without any Literals, it covers every Row.
+    pub fn universe(num_variables: usize) -> Self {
+        Self {
+            v: vec![TruthValue::DontCare; num_variables],
+        }
+    }
+    /// Term covering exactly the Rows covered by both Terms, None if there are no such Rows.
+    pub fn intersect(&self, other: &Minterm) -> Option<Minterm> {
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .map(|(t1, t2)| match (t1, t2) {
+                (TruthValue::DontCare, t) | (t, TruthValue::DontCare) => Some(*t),
+                (t1, t2) if t1 == t2 => Some(*t1),
+                _ => None,
+            })
+            .collect::<Option<Vec<TruthValue>>>()
+            .map(|v| Self { v })
+    }
+    /// Checks if some Row is covered by both Terms.
+    pub fn intersects(&self, other: &Minterm) -> bool {
+        self.v.iter().zip(other.v.iter()).all(|(t1, t2)| {
+            matches!(t1, TruthValue::DontCare) || matches!(t2, TruthValue::DontCare) || t1 == t2
+        })
+    }
+    /// Cofactor of this Term with respect to `other`: the Positions fixed by `other` become
+    /// `DontCare`. None if the Terms don't intersect.
+    pub fn cofactor(&self, other: &Minterm) -> Option<Minterm> {
+        if (!self.intersects(other)) {
+            return None;
+        }
+        Some(Self {
+            v: self
+                .v
+                .iter()
+                .zip(other.v.iter())
+                .map(|(t1, t2)| match t2 {
+                    TruthValue::DontCare => *t1,
+                    _ => TruthValue::DontCare,
+                })
+                .collect(),
+        })
+    }
+    /// Indices of every Row of the Truth-Table covered by this Term.
+    pub fn indices(&self) -> Vec<usize> {
+        let mut indices = vec![0usize];
+        for t in self.v.iter() {
+            indices = match t {
+                TruthValue::True => indices.into_iter().map(|i| (i << 1) | 1).collect(),
+                TruthValue::False => indices.into_iter().map(|i| i << 1).collect(),
+                TruthValue::DontCare => indices
+                    .into_iter()
+                    .flat_map(|i| [i << 1, (i << 1) | 1])
+                    .collect(),
+            };
+        }
+        indices.sort_unstable();
+        indices
+    }
+    /// Conjunction of the Literals, the Variables are the Integer-Identifiers of the Positions.
+    /// A Term without Literals is true.
+    pub fn to_ast(&self) -> ASTNode {
+        self.v
+            .iter()
+            .enumerate()
+            .filter_map(|(i, t)| match t {
+                TruthValue::True => Some(ASTNode::Identifier(i)),
+                TruthValue::False => Some(ASTNode::Identifier(i).negate()),
+                TruthValue::DontCare => None,
+            })
+            .reduce(|lhs, rhs| ASTNode::infix(InfixOperator::And, lhs, rhs))
+            .unwrap_or(ASTNode::Literal(true))
+    }
+    /// Disjunction of the complemented Literals, the Maxterm that is false exactly in the Rows
+    /// this Term covers. A Term without Literals gives false.
+    pub fn to_clause_ast(&self) -> ASTNode {
+        self.v
+            .iter()
+            .enumerate()
+            .filter_map(|(i, t)| match t {
+                TruthValue::True => Some(ASTNode::Identifier(i).negate()),
+                TruthValue::False => Some(ASTNode::Identifier(i)),
+                TruthValue::DontCare => None,
+            })
+            .reduce(|lhs, rhs| ASTNode::infix(InfixOperator::Or, lhs, rhs))
+            .unwrap_or(ASTNode::Literal(false))
+    }
+}
+
+/// Prints the Term with one Character per Position, e.g. "TXF".
+impl Display for Minterm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for t in self.v.iter() {
+            write!(f, "{}", t)?;
+        }
+        Ok(())
+    }
+}
+
 impl From<Vec<TruthValue>> for Minterm {
     fn from(v: Vec<TruthValue>) -> Self {
         Self { v }
@@ -119,7 +298,7 @@ impl BitAnd for Minterm {
             v: self
                 .v
                 .into_iter()
-                .zip(rhs.v.into_iter())
+                .zip(rhs.v)
                 .map(|(t1, t2)| t1 & t2)
                 .collect(),
         }
@@ -129,12 +308,7 @@ impl BitAnd for Minterm {
 impl BitAndAssign for Minterm {
     fn bitand_assign(&mut self, rhs: Self) {
         *self = Self {
-            v: self
-                .v
-                .iter()
-                .zip(rhs.v.into_iter())
-                .map(|(t1, t2)| *t1 & t2)
-                .collect(),
+            v: self.v.iter().zip(rhs.v).map(|(t1, t2)| *t1 & t2).collect(),
         }
     }
 }
@@ -147,7 +321,7 @@ impl BitOr for Minterm {
             v: self
                 .v
                 .into_iter()
-                .zip(rhs.v.into_iter())
+                .zip(rhs.v)
                 .map(|(t1, t2)| t1 | t2)
                 .collect(),
         }
@@ -157,12 +331,7 @@ impl BitOr for Minterm {
 impl BitOrAssign for Minterm {
     fn bitor_assign(&mut self, rhs: Self) {
         *self = Self {
-            v: self
-                .v
-                .iter()
-                .zip(rhs.v.into_iter())
-                .map(|(t1, t2)| *t1 | t2)
-                .collect(),
+            v: self.v.iter().zip(rhs.v).map(|(t1, t2)| *t1 | t2).collect(),
         }
     }
 }
@@ -186,7 +355,7 @@ impl Shr for Minterm {
             v: self
                 .v
                 .into_iter()
-                .zip(rhs.v.into_iter())
+                .zip(rhs.v)
                 .map(|(t1, t2)| t1 >> t2)
                 .collect(),
         }
@@ -196,12 +365,7 @@ impl Shr for Minterm {
 impl ShrAssign for Minterm {
     fn shr_assign(&mut self, rhs: Self) {
         *self = Self {
-            v: self
-                .v
-                .iter()
-                .zip(rhs.v.into_iter())
-                .map(|(t1, t2)| *t1 >> t2)
-                .collect(),
+            v: self.v.iter().zip(rhs.v).map(|(t1, t2)| *t1 >> t2).collect(),
         }
     }
 }
@@ -220,3 +384,37 @@ impl IndexMut<usize> for Minterm {
         &mut self.v[index]
     }
 }
+
+/// Disjunction of the Terms, false if there are none.
+pub fn sum_of_products(terms: &[Minterm]) -> ASTNode {
+    terms
+        .iter()
+        .map(Minterm::to_ast)
+        .reduce(|lhs, rhs| ASTNode::infix(InfixOperator::Or, lhs, rhs))
+        .unwrap_or(ASTNode::Literal(false))
+}
+
+/// Conjunction of the Maxterms of the Terms, i.e. false exactly in the Rows covered by one of
+/// the Terms. True if there are none.
+pub fn product_of_sums(terms: &[Minterm]) -> ASTNode {
+    terms
+        .iter()
+        .map(Minterm::to_clause_ast)
+        .reduce(|lhs, rhs| ASTNode::infix(InfixOperator::And, lhs, rhs))
+        .unwrap_or(ASTNode::Literal(true))
+}
+
+/// Sorts the Terms like they would be written by Hand, positive Literals of the first
+/// Variables first.
+pub(crate) fn sort_terms(terms: &mut [Minterm]) {
+    terms.sort_by_key(|term| {
+        term.v
+            .iter()
+            .map(|t| match t {
+                TruthValue::True => 0,
+                TruthValue::False => 1,
+                TruthValue::DontCare => 2,
+            })
+            .collect::<Vec<u8>>()
+    });
+}
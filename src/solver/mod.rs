@@ -0,0 +1,9 @@
+pub mod espresso;
+pub mod minterm;
+pub mod petrick;
+pub mod quine_mccluskey;
+pub mod sat;
+
+pub use minterm::{Minterm, TruthValue};
+pub use petrick::CoverCost;
+pub use sat::SatResult;
@@ -0,0 +1,182 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::solver::minterm::{sort_terms, Minterm};
+
+use std::collections::BTreeSet;
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// What makes one Cover cheaper than another. The other Measure breaks Ties.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum CoverCost {
+    /// Fewest Product-Terms, then fewest Literals.
+    #[default]
+    Terms,
+    /// Fewest Literals over all Product-Terms, then fewest Product-Terms.
+    Literals,
+}
+
+impl CoverCost {
+    /// Cost of a Cover, smaller is better.
+    pub fn of(&self, cover: &[Minterm]) -> (usize, usize) {
+        let terms = cover.len();
+        let literals = cover.iter().map(Minterm::num_literals).sum();
+        match self {
+            CoverCost::Terms => (terms, literals),
+            CoverCost::Literals => (literals, terms),
+        }
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Every Cover of the Rows in `on_set` by the given Prime-Implicants that is minimal for the
+/// Cost-Function, found with Petrick's Method.
+///
+/// Essential Prime-Implicants are part of every Cover and are taken first. For each remaining
+/// Row, the Sum of the Implicants covering it is built, and the Product of these Sums is
+/// multiplied out into a Sum of Products, dropping absorbed Products and Products that are
+/// more expensive than a greedily found Cover along the Way. Each remaining Product is an
+/// irredundant Cover.
+///
+/// # Panics
+/// If some Row isn't covered by any of the Prime-Implicants.
+pub fn minimal_covers(primes: &[Minterm], on_set: &[usize], cost: CoverCost) -> Vec<Vec<Minterm>> {
+    covers(primes, on_set, cost, true)
+}
+
+/// `minimal_covers` without the Bound from the greedy Cover, which must give the same Result.
+pub(crate) fn minimal_covers_unbounded(
+    primes: &[Minterm],
+    on_set: &[usize],
+    cost: CoverCost,
+) -> Vec<Vec<Minterm>> {
+    covers(primes, on_set, cost, false)
+}
+
+fn covers(
+    primes: &[Minterm],
+    on_set: &[usize],
+    cost: CoverCost,
+    bounded: bool,
+) -> Vec<Vec<Minterm>> {
+    let rows: BTreeSet<usize> = on_set.iter().copied().collect();
+    let covering = |row: usize| -> BTreeSet<usize> {
+        (0..primes.len())
+            .filter(|idx| primes[*idx].covers(row))
+            .collect()
+    };
+
+    let mut essential: BTreeSet<usize> = BTreeSet::new();
+    for row in rows.iter() {
+        let sum = covering(*row);
+        assert!(
+            !sum.is_empty(),
+            "row {} isn't covered by any implicant",
+            row
+        );
+        if (sum.len() == 1) {
+            essential.extend(sum);
+        }
+    }
+
+    let remaining: Vec<(usize, BTreeSet<usize>)> = rows
+        .iter()
+        .filter(|row| !essential.iter().any(|idx| primes[*idx].covers(**row)))
+        .map(|row| (*row, covering(*row)))
+        .collect();
+
+    // Products that are already more expensive than some known Cover can't become minimal,
+    // since adding Implicants never makes a Cover cheaper.
+    let bound = cost.of(&to_cover(
+        primes,
+        &greedy_cover(primes, &essential, &remaining),
+    ));
+    let mut products: Vec<BTreeSet<usize>> = vec![BTreeSet::new()];
+    for (_, sum) in remaining.iter() {
+        products = multiply(products, sum);
+        if (bounded) {
+            products.retain(|product| {
+                cost.of(&to_cover(
+                    primes,
+                    &essential.union(product).copied().collect(),
+                )) <= bound
+            });
+        }
+    }
+
+    let mut covers: Vec<Vec<Minterm>> = products
+        .into_iter()
+        .map(|product| {
+            let mut cover = to_cover(primes, &essential.union(&product).copied().collect());
+            sort_terms(&mut cover);
+            cover
+        })
+        .collect();
+    let best = covers.iter().map(|cover| cost.of(cover)).min();
+    covers.retain(|cover| Some(cost.of(cover)) == best);
+    covers.sort();
+    covers
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Some Cover, found by taking the Implicant covering the most remaining Rows until every Row
+/// is covered.
+fn greedy_cover(
+    primes: &[Minterm],
+    essential: &BTreeSet<usize>,
+    remaining: &[(usize, BTreeSet<usize>)],
+) -> BTreeSet<usize> {
+    let mut chosen = essential.clone();
+    let mut uncovered: Vec<&BTreeSet<usize>> = remaining.iter().map(|(_, sum)| sum).collect();
+    while !uncovered.is_empty() {
+        let best = (0..primes.len())
+            .filter(|idx| !chosen.contains(idx))
+            .max_by_key(|idx| {
+                let covered = uncovered.iter().filter(|sum| sum.contains(idx)).count();
+                // Prefer Implicants with fewer Literals on Ties
+                (covered, usize::MAX - primes[*idx].num_literals())
+            })
+            .unwrap();
+        uncovered.retain(|sum| !sum.contains(&best));
+        chosen.insert(best);
+    }
+    chosen
+}
+
+fn to_cover(primes: &[Minterm], indices: &BTreeSet<usize>) -> Vec<Minterm> {
+    indices.iter().map(|idx| primes[*idx].clone()).collect()
+}
+
+/// Multiplies a Sum of Products with a Sum of single Implicants, keeping only Products that
+/// aren't absorbed by another one (X + XY = X).
+fn multiply(products: Vec<BTreeSet<usize>>, sum: &BTreeSet<usize>) -> Vec<BTreeSet<usize>> {
+    let mut result: BTreeSet<BTreeSet<usize>> = BTreeSet::new();
+    for product in products {
+        if (!product.is_disjoint(sum)) {
+            // X (X + Y) = X
+            result.insert(product);
+            continue;
+        }
+        for idx in sum {
+            let mut extended = product.clone();
+            extended.insert(*idx);
+            result.insert(extended);
+        }
+    }
+    let result: Vec<BTreeSet<usize>> = result.into_iter().collect();
+    result
+        .iter()
+        .filter(|product| {
+            !result
+                .iter()
+                .any(|other| other.len() < product.len() && other.is_subset(product))
+        })
+        .cloned()
+        .collect()
+}
+
+/* --------------------------------------------------------------------------------------------- */
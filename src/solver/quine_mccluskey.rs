@@ -0,0 +1,97 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::solver::{
+    minterm::{Minterm, TruthValue},
+    petrick::{self, CoverCost},
+};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Minimal Sum-of-Products Cover of the Function that is true exactly for the Rows of the
+/// Truth-Table in `on_set`, with the fewest Product-Terms.
+pub fn minimize(on_set: &[usize], num_variables: usize) -> Vec<Minterm> {
+    minimize_with(on_set, &[], num_variables, CoverCost::default())
+}
+
+/// Minimal Cover of the Rows in `on_set` for the given Cost-Function. The Rows in
+/// `dont_cares` may be covered as well if that makes the Cover cheaper.
+pub fn minimize_with(
+    on_set: &[usize],
+    dont_cares: &[usize],
+    num_variables: usize,
+    cost: CoverCost,
+) -> Vec<Minterm> {
+    let terms: Vec<Minterm> = on_set
+        .iter()
+        .chain(dont_cares.iter())
+        .map(|idx| Minterm::from_index(*idx, num_variables))
+        .collect();
+    let primes = prime_implicants(&terms);
+    select_cover(&primes, on_set, cost)
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Finds every Prime-Implicant of the Function covered by the given Terms.
+///
+/// Terms whose Positions differ in exactly one Place are merged repeatedly, turning that Place
+/// into a `DontCare`. Terms that can't be merged with any other one are prime.
+pub fn prime_implicants(terms: &[Minterm]) -> Vec<Minterm> {
+    let mut current: Vec<Minterm> = terms
+        .iter()
+        .cloned()
+        .collect::<BTreeSet<Minterm>>()
+        .into_iter()
+        .collect();
+    let mut primes: BTreeSet<Minterm> = BTreeSet::new();
+
+    while !current.is_empty() {
+        // Only Terms whose Number of positive Literals differs by one can be merged.
+        let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (idx, term) in current.iter().enumerate() {
+            groups.entry(term.num_true()).or_default().push(idx);
+        }
+
+        let mut merged = vec![false; current.len()];
+        let mut next: BTreeSet<Minterm> = BTreeSet::new();
+        for (ones, group) in groups.iter() {
+            let Some(neighbours) = groups.get(&(ones + 1)) else {
+                continue;
+            };
+            for i in group {
+                for j in neighbours {
+                    if let Some(term) = current[*i].merge(&current[*j]) {
+                        merged[*i] = true;
+                        merged[*j] = true;
+                        next.insert(term);
+                    }
+                }
+            }
+        }
+
+        for (term, merged) in current.into_iter().zip(merged) {
+            if (!merged) {
+                primes.insert(term);
+            }
+        }
+        current = next.into_iter().collect();
+    }
+    primes.into_iter().collect()
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Selects Prime-Implicants that together cover every Row in `on_set` at minimal Cost.
+/// If there are several minimal Covers, the first one of `petrick::minimal_covers` is taken.
+pub fn select_cover(primes: &[Minterm], on_set: &[usize], cost: CoverCost) -> Vec<Minterm> {
+    petrick::minimal_covers(primes, on_set, cost)
+        .into_iter()
+        .next()
+        .unwrap_or_default()
+}
+
+/* --------------------------------------------------------------------------------------------- */
@@ -0,0 +1,506 @@
+#![allow(unused)]
+
+/* --------------------------------------------------------------------------------------------- */
+
+use crate::parser::normal_form::{Clause, Cnf, Literal};
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Outcome of `Solver::solve`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum SatResult {
+    /// The Value of every Variable in an Assignment that satisfies every Clause.
+    Sat(Vec<bool>),
+    Unsat,
+}
+
+/// Conflict-driven Clause-learning SAT-Solver.
+///
+/// Clauses are propagated with two watched Literals. On a Conflict the Solver learns the
+/// first-UIP Clause, jumps back to the second-highest Decision-Level in it and bumps the
+/// Activity of the involved Variables (VSIDS), the next Decision takes the most active
+/// unassigned Variable with its last Value. The Search restarts after a Number of Conflicts
+/// that follows the Luby-Sequence, and every Restart drops the less active half of the learnt
+/// Clauses once there are too many of them.
+#[derive(Debug, Clone)]
+pub struct Solver {
+    clauses: Vec<ClauseData>,
+    /// Clauses watching each Literal, indexed by `code`.
+    watches: Vec<Vec<usize>>,
+    values: Vec<Option<bool>>,
+    /// Decision-Level and Reason-Clause of every assigned Variable.
+    level: Vec<usize>,
+    reason: Vec<Option<usize>>,
+    /// Assigned Literals in Order, split into Decision-Levels at `trail_lim`.
+    trail: Vec<usize>,
+    trail_lim: Vec<usize>,
+    /// Position in the Trail up to which Literals have been propagated.
+    queue_head: usize,
+    activity: Vec<f64>,
+    variable_increment: f64,
+    clause_increment: f64,
+    order: VariableOrder,
+    /// Last Value of every Variable, which the next Decision on it reuses.
+    phase: Vec<bool>,
+    max_learnts: usize,
+    conflicts: usize,
+    /// Set when the Clauses are unsatisfiable without any Decision.
+    unsat: bool,
+}
+
+#[derive(Debug, Clone)]
+struct ClauseData {
+    /// The first two Literals are the watched ones. A Clause that is the Reason of an
+    /// Assignment has the implied Literal first.
+    literals: Vec<usize>,
+    learnt: bool,
+    activity: f64,
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Solver {
+    const VARIABLE_DECAY: f64 = 0.95;
+    const CLAUSE_DECAY: f64 = 0.999;
+    /// Conflicts per Unit of the Luby-Sequence.
+    const RESTART_BASE: usize = 100;
+
+    pub fn new(num_variables: usize) -> Self {
+        let mut solver = Self {
+            clauses: Vec::new(),
+            watches: Vec::new(),
+            values: Vec::new(),
+            level: Vec::new(),
+            reason: Vec::new(),
+            trail: Vec::new(),
+            trail_lim: Vec::new(),
+            queue_head: 0,
+            activity: Vec::new(),
+            variable_increment: 1.0,
+            clause_increment: 1.0,
+            order: VariableOrder::default(),
+            phase: Vec::new(),
+            max_learnts: 0,
+            conflicts: 0,
+            unsat: false,
+        };
+        solver.reserve_variables(num_variables);
+        solver
+    }
+    /// Solver for the Clauses, over at least `num_variables` Variables.
+    pub fn from_cnf(cnf: &Cnf, num_variables: usize) -> Self {
+        let mut solver = Self::new(num_variables);
+        for clause in cnf.clauses.iter() {
+            solver.add_clause(clause);
+        }
+        solver
+    }
+    pub fn num_variables(&self) -> usize {
+        self.values.len()
+    }
+    /// Number of Conflicts the Search ran into so far.
+    pub fn num_conflicts(&self) -> usize {
+        self.conflicts
+    }
+    /// Adds a Clause, together with the Variables it mentions.
+    pub fn add_clause(&mut self, clause: &Clause) {
+        if let Some(max) = clause.literals.iter().map(|l| l.variable).max() {
+            self.reserve_variables(max + 1);
+        }
+        self.backtrack(0);
+        let mut literals: Vec<usize> = clause.literals.iter().map(|l| code(*l)).collect();
+        literals.sort_unstable();
+        literals.dedup();
+        // Drop Clauses that are always true, and Literals that are false at Level 0
+        if (literals.windows(2).any(|w| w[0] ^ 1 == w[1])
+            || literals.iter().any(|l| self.value(*l) == Some(true)))
+        {
+            return;
+        }
+        literals.retain(|l| self.value(*l).is_none());
+        match literals.len() {
+            0 => self.unsat = true,
+            1 => {
+                self.enqueue(literals[0], None);
+                if (self.propagate().is_some()) {
+                    self.unsat = true;
+                }
+            }
+            _ => {
+                self.attach(literals, false);
+            }
+        }
+    }
+    /// Searches for an Assignment that satisfies every Clause.
+    pub fn solve(&mut self) -> SatResult {
+        if (self.unsat) {
+            return SatResult::Unsat;
+        }
+        self.max_learnts = self.max_learnts.max(self.clauses.len() / 3 + 100);
+        let mut restarts = 0;
+        loop {
+            let budget = luby(restarts) * Self::RESTART_BASE;
+            if let Some(result) = self.search(budget) {
+                return result;
+            }
+            restarts += 1;
+            self.backtrack(0);
+            if (self.num_learnts() >= self.max_learnts) {
+                self.reduce_learnts();
+                self.max_learnts += self.max_learnts / 10;
+            }
+        }
+    }
+}
+
+/// Checks if the Clauses have a Model, see `Solver`.
+pub fn solve(cnf: &Cnf, num_variables: usize) -> SatResult {
+    Solver::from_cnf(cnf, num_variables).solve()
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Solver {
+    /// Runs CDCL until the Result is known or the Number of Conflicts is used up.
+    fn search(&mut self, max_conflicts: usize) -> Option<SatResult> {
+        let mut conflicts = 0;
+        loop {
+            if let Some(conflict) = self.propagate() {
+                self.conflicts += 1;
+                conflicts += 1;
+                if (self.trail_lim.is_empty()) {
+                    self.unsat = true;
+                    return Some(SatResult::Unsat);
+                }
+                let (learnt, level) = self.analyze(conflict);
+                self.backtrack(level);
+                if (learnt.len() == 1) {
+                    self.enqueue(learnt[0], None);
+                } else {
+                    let asserting = learnt[0];
+                    let clause = self.attach(learnt, true);
+                    self.bump_clause(clause);
+                    self.enqueue(asserting, Some(clause));
+                }
+                self.variable_increment /= Self::VARIABLE_DECAY;
+                self.clause_increment /= Self::CLAUSE_DECAY;
+                continue;
+            }
+            if (conflicts >= max_conflicts) {
+                return None;
+            }
+            let Some(variable) = self.next_decision() else {
+                let model = self.values.iter().map(|v| v.unwrap()).collect();
+                return Some(SatResult::Sat(model));
+            };
+            self.trail_lim.push(self.trail.len());
+            self.enqueue(2 * variable + !self.phase[variable] as usize, None);
+        }
+    }
+    /// Propagates the Assignments on the Trail, returns a Clause with every Literal false.
+    fn propagate(&mut self) -> Option<usize> {
+        while self.queue_head < self.trail.len() {
+            let falsified = self.trail[self.queue_head] ^ 1;
+            self.queue_head += 1;
+            let watching = std::mem::take(&mut self.watches[falsified]);
+            let mut kept = Vec::with_capacity(watching.len());
+            let mut conflict = None;
+            for (idx, clause) in watching.iter().copied().enumerate() {
+                if (conflict.is_some()) {
+                    kept.push(clause);
+                    continue;
+                }
+                let literals = &mut self.clauses[clause].literals;
+                if (literals[0] == falsified) {
+                    literals.swap(0, 1);
+                }
+                let other = literals[0];
+                if (value_of(&self.values, other) == Some(true)) {
+                    kept.push(clause);
+                    continue;
+                }
+                // Watch another Literal that isn't false, if there is one
+                let replacement = (2..literals.len())
+                    .find(|k| value_of(&self.values, literals[*k]) != Some(false));
+                if let Some(k) = replacement {
+                    literals.swap(1, k);
+                    let watched = literals[1];
+                    self.watches[watched].push(clause);
+                    continue;
+                }
+                kept.push(clause);
+                match value_of(&self.values, other) {
+                    Some(false) => conflict = Some(clause),
+                    _ => self.enqueue(other, Some(clause)),
+                }
+            }
+            self.watches[falsified] = kept;
+            if (conflict.is_some()) {
+                return conflict;
+            }
+        }
+        None
+    }
+    /// First-UIP Clause of the Conflict and the Level to jump back to. The asserting Literal
+    /// comes first, followed by a Literal of the Level to jump back to.
+    fn analyze(&mut self, conflict: usize) -> (Vec<usize>, usize) {
+        let current = self.trail_lim.len();
+        let mut seen = vec![false; self.num_variables()];
+        let mut learnt = vec![0];
+        let mut pending = 0;
+        let mut clause = conflict;
+        let mut idx = self.trail.len();
+        loop {
+            if (self.clauses[clause].learnt) {
+                self.bump_clause(clause);
+            }
+            // The first Literal of a Reason is the implied one, which is resolved away
+            let skip = usize::from(clause != conflict);
+            for literal in self.clauses[clause].literals.clone().into_iter().skip(skip) {
+                let variable = literal / 2;
+                if (seen[variable] || self.level[variable] == 0) {
+                    continue;
+                }
+                seen[variable] = true;
+                self.bump_variable(variable);
+                if (self.level[variable] == current) {
+                    pending += 1;
+                } else {
+                    learnt.push(literal);
+                }
+            }
+            // Latest Literal of the Trail that is involved
+            loop {
+                idx -= 1;
+                if (seen[self.trail[idx] / 2]) {
+                    break;
+                }
+            }
+            let literal = self.trail[idx];
+            seen[literal / 2] = false;
+            pending -= 1;
+            if (pending == 0) {
+                learnt[0] = literal ^ 1;
+                break;
+            }
+            clause = self.reason[literal / 2].unwrap();
+        }
+        let level = match (1..learnt.len()).max_by_key(|i| self.level[learnt[*i] / 2]) {
+            Some(i) => {
+                learnt.swap(1, i);
+                self.level[learnt[1] / 2]
+            }
+            None => 0,
+        };
+        (learnt, level)
+    }
+    fn backtrack(&mut self, level: usize) {
+        if (self.trail_lim.len() <= level) {
+            return;
+        }
+        for literal in self.trail.drain(self.trail_lim[level]..) {
+            let variable = literal / 2;
+            self.phase[variable] = literal & 1 == 0;
+            self.values[variable] = None;
+            self.reason[variable] = None;
+            self.order.insert(variable, &self.activity);
+        }
+        self.trail_lim.truncate(level);
+        self.queue_head = self.trail.len();
+    }
+    fn next_decision(&mut self) -> Option<usize> {
+        while let Some(variable) = self.order.pop(&self.activity) {
+            if (self.values[variable].is_none()) {
+                return Some(variable);
+            }
+        }
+        None
+    }
+    /// Drops the less active half of the learnt Clauses, keeping binary ones. Only called at
+    /// Level 0, where no Reason is needed anymore, so the Clauses can be renumbered.
+    fn reduce_learnts(&mut self) {
+        debug_assert!(self.trail_lim.is_empty());
+        let mut learnt: Vec<usize> = (0..self.clauses.len())
+            .filter(|c| self.clauses[*c].learnt && self.clauses[*c].literals.len() > 2)
+            .collect();
+        learnt.sort_by(|a, b| {
+            let activity = |c: &usize| self.clauses[*c].activity;
+            activity(a).total_cmp(&activity(b))
+        });
+        let mut dropped = vec![false; self.clauses.len()];
+        for clause in learnt.iter().take(learnt.len() / 2) {
+            dropped[*clause] = true;
+        }
+        let clauses = std::mem::take(&mut self.clauses);
+        self.clauses = clauses
+            .into_iter()
+            .zip(dropped)
+            .filter(|(_, dropped)| !dropped)
+            .map(|(clause, _)| clause)
+            .collect();
+        self.reason.iter_mut().for_each(|r| *r = None);
+        self.watches.iter_mut().for_each(Vec::clear);
+        for (idx, clause) in self.clauses.iter().enumerate() {
+            self.watches[clause.literals[0]].push(idx);
+            self.watches[clause.literals[1]].push(idx);
+        }
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+impl Solver {
+    fn reserve_variables(&mut self, num_variables: usize) {
+        while self.values.len() < num_variables {
+            let variable = self.values.len();
+            self.values.push(None);
+            self.level.push(0);
+            self.reason.push(None);
+            self.activity.push(0.0);
+            self.phase.push(false);
+            self.watches.push(Vec::new());
+            self.watches.push(Vec::new());
+            self.order.insert(variable, &self.activity);
+        }
+    }
+    fn num_learnts(&self) -> usize {
+        self.clauses.iter().filter(|c| c.learnt).count()
+    }
+    fn value(&self, literal: usize) -> Option<bool> {
+        value_of(&self.values, literal)
+    }
+    fn enqueue(&mut self, literal: usize, reason: Option<usize>) {
+        let variable = literal / 2;
+        self.values[variable] = Some(literal & 1 == 0);
+        self.level[variable] = self.trail_lim.len();
+        self.reason[variable] = reason;
+        self.trail.push(literal);
+    }
+    /// Stores the Clause and watches its first two Literals.
+    fn attach(&mut self, literals: Vec<usize>, learnt: bool) -> usize {
+        let idx = self.clauses.len();
+        self.watches[literals[0]].push(idx);
+        self.watches[literals[1]].push(idx);
+        self.clauses.push(ClauseData {
+            literals,
+            learnt,
+            activity: 0.0,
+        });
+        idx
+    }
+    fn bump_variable(&mut self, variable: usize) {
+        self.activity[variable] += self.variable_increment;
+        if (self.activity[variable] > 1e100) {
+            self.activity.iter_mut().for_each(|a| *a *= 1e-100);
+            self.variable_increment *= 1e-100;
+        }
+        self.order.increase(variable, &self.activity);
+    }
+    fn bump_clause(&mut self, clause: usize) {
+        self.clauses[clause].activity += self.clause_increment;
+        if (self.clauses[clause].activity > 1e20) {
+            self.clauses.iter_mut().for_each(|c| c.activity *= 1e-20);
+            self.clause_increment *= 1e-20;
+        }
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Binary Max-Heap of Variables by Activity, which knows the Position of every Variable so
+/// it can move a Variable up when its Activity grows.
+#[derive(Debug, Clone, Default)]
+struct VariableOrder {
+    heap: Vec<usize>,
+    position: Vec<Option<usize>>,
+}
+
+impl VariableOrder {
+    fn insert(&mut self, variable: usize, activity: &[f64]) {
+        if (self.position.len() <= variable) {
+            self.position.resize(variable + 1, None);
+        }
+        if (self.position[variable].is_some()) {
+            return;
+        }
+        self.position[variable] = Some(self.heap.len());
+        self.heap.push(variable);
+        self.sift_up(self.heap.len() - 1, activity);
+    }
+    fn increase(&mut self, variable: usize, activity: &[f64]) {
+        if let Some(pos) = self.position[variable] {
+            self.sift_up(pos, activity);
+        }
+    }
+    fn pop(&mut self, activity: &[f64]) -> Option<usize> {
+        let top = *self.heap.first()?;
+        let last = self.heap.pop().unwrap();
+        self.position[top] = None;
+        if (!self.heap.is_empty()) {
+            self.heap[0] = last;
+            self.position[last] = Some(0);
+            self.sift_down(0, activity);
+        }
+        Some(top)
+    }
+    fn sift_up(&mut self, mut pos: usize, activity: &[f64]) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if (activity[self.heap[parent]] >= activity[self.heap[pos]]) {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+    }
+    fn sift_down(&mut self, mut pos: usize, activity: &[f64]) {
+        loop {
+            let larger = [2 * pos + 1, 2 * pos + 2]
+                .into_iter()
+                .filter(|child| *child < self.heap.len())
+                .max_by(|a, b| activity[self.heap[*a]].total_cmp(&activity[self.heap[*b]]));
+            match larger {
+                Some(child) if activity[self.heap[child]] > activity[self.heap[pos]] => {
+                    self.swap(pos, child);
+                    pos = child;
+                }
+                _ => break,
+            }
+        }
+    }
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.position[self.heap[a]] = Some(a);
+        self.position[self.heap[b]] = Some(b);
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+
+/// Literals are numbered `2 * variable` and `2 * variable + 1` for the Negation, so the
+/// Negation of a Literal is `code ^ 1`.
+fn code(literal: Literal) -> usize {
+    2 * literal.variable + literal.negated as usize
+}
+
+fn value_of(values: &[Option<bool>], literal: usize) -> Option<bool> {
+    values[literal / 2].map(|value| value != (literal & 1 == 1))
+}
+
+/// Element of the Luby-Sequence 1, 1, 2, 1, 1, 2, 4, 1, 1, 2, 1, 1, 2, 4, 8, ...
+fn luby(mut idx: usize) -> usize {
+    // Find the finite Subsequence that contains the Index, and its Size
+    let (mut size, mut exponent) = (1, 0);
+    while size < idx + 1 {
+        size = 2 * size + 1;
+        exponent += 1;
+    }
+    while size - 1 != idx {
+        size = (size - 1) / 2;
+        exponent -= 1;
+        idx %= size;
+    }
+    1 << exponent
+}
+
+/* --------------------------------------------------------------------------------------------- */